@@ -0,0 +1,108 @@
+//! Per-phase benchmarks (parse, part 1, part 2) for every day, over the
+//! committed input, over copies of it scaled up several times and over a
+//! generated input.
+//!
+//! Run with `cargo bench -p aoc`, or `cargo bench -p aoc -- day_5` for one day.
+
+use aoc::DAYS;
+use aoc_common::{read_input, Day, Part};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const SCALES: [usize; 2] = [2, 4];
+/// The size, in records, of the generated input every day is benched on.
+const GENERATED_SIZE: usize = 1000;
+
+fn bench_day(c: &mut Criterion, day: &Day) {
+    let input = read_input(day.input).unwrap();
+    let mut inputs = vec![("real".to_string(), input.clone())];
+    for &factor in SCALES.iter() {
+        if let Some(scaled) = scale(day.number, &input, factor) {
+            inputs.push((format!("x{}", factor), scaled));
+        }
+    }
+    inputs.push(("generated".to_string(), (day.generate)(0, GENERATED_SIZE)));
+
+    let mut group = c.benchmark_group(day.name);
+    group.sample_size(10);
+    for (name, input) in inputs.iter() {
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| day.solution.parse_any(input).unwrap())
+        });
+        let parsed = day.solution.parse_any(input).unwrap();
+        for &part in Part::ALL.iter() {
+            let id = BenchmarkId::new(format!("part_{}", part), name);
+            group.bench_with_input(id, &*parsed, |b, parsed| {
+                b.iter(|| day.solution.part_any(part, parsed))
+            });
+        }
+    }
+    group.finish();
+}
+
+/// A bigger input of the same shape as `input`, made of `factor` copies of
+/// it laid side by side so the copies do not collide. `None` for puzzles
+/// whose input cannot simply be repeated.
+fn scale(day: u32, input: &str, factor: usize) -> Option<String> {
+    let mut lines = vec![];
+    match day {
+        1 | 2 => {
+            for _ in 0..factor {
+                lines.extend(input.lines().map(String::from));
+            }
+        }
+        3 => {
+            let claims: Vec<Vec<usize>> = input.lines().map(numbers).collect();
+            let width = claims.iter().map(|c| c[1] + c[3]).max().unwrap_or(0);
+            for k in 0..factor {
+                for c in claims.iter() {
+                    let id = c[0] + k * claims.len();
+                    let x = c[1] + k * width;
+                    lines.push(format!("#{} @ {},{}: {}x{}", id, x, c[2], c[3], c[4]));
+                }
+            }
+        }
+        4 => {
+            // Each copy happens a year after the one before.
+            for k in 0..factor {
+                for line in input.lines() {
+                    let year: usize = line[1..5].parse().unwrap();
+                    lines.push(format!("[{}{}", year + k, &line[5..]));
+                }
+            }
+        }
+        5 => lines.push(input.trim().repeat(factor)),
+        6 => {
+            let coords: Vec<Vec<usize>> = input.lines().map(numbers).collect();
+            let width = coords.iter().map(|c| c[0]).max().unwrap_or(0) + 1;
+            for k in 0..factor {
+                for c in coords.iter() {
+                    lines.push(format!("{}, {}", c[0] + k * width, c[1]));
+                }
+            }
+        }
+        // Hang every copy of the tree off a new root without metadata.
+        8 => lines.push(format!(
+            "{} 0 {}",
+            factor,
+            vec![input.trim(); factor].join(" ")
+        )),
+        _ => return None,
+    }
+    Some(lines.join("\n"))
+}
+
+fn numbers(line: &str) -> Vec<usize> {
+    line.split(|c: char| !c.is_ascii_digit())
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().unwrap())
+        .collect()
+}
+
+fn solutions(c: &mut Criterion) {
+    for day in DAYS.iter() {
+        bench_day(c, day);
+    }
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
@@ -0,0 +1,345 @@
+//! Talking to the Advent of Code site: `aoc fetch` downloads puzzle inputs
+//! into the place each day reads them from, and `aoc submit` sends answers
+//! back, remembering what the site said about each.
+
+use aoc_common::{Day, Part, Result};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The site the inputs come from unless `AOC_BASE_URL` says otherwise.
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2018;
+
+/// A logged in session with the site, or with anything that serves the
+/// same paths.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    /// The client configured by `AOC_BASE_URL` and `AOC_SESSION`, the value
+    /// of the `session` cookie the site sets on logging in. The session is
+    /// only needed once something is actually requested.
+    pub fn from_env() -> Client {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_string());
+        Client::new(&base_url, env::var("AOC_SESSION").ok())
+    }
+
+    fn session(&self) -> Result<&str> {
+        self.session
+            .as_deref()
+            .ok_or_else(|| "Set AOC_SESSION to the session cookie of a logged in browser".into())
+    }
+
+    /// Download the puzzle input of day `number`.
+    pub fn input(&self, number: u32) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, number);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .call()
+            .map_err(|error| describe(&url, error))?;
+        response
+            .into_string()
+            .map_err(|error| format!("Could not read {}: {}", url, error).into())
+    }
+
+    /// Send `answer` to `part` of day `number` and read the verdict.
+    pub fn submit(&self, number: u32, part: Part, answer: &str) -> Result<Verdict> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, number);
+        let level = part.to_string();
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|error| describe(&url, error))?;
+        let page = response
+            .into_string()
+            .map_err(|error| format!("Could not read {}: {}", url, error))?;
+        parse_verdict(&page)
+    }
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// Read the verdict out of the page the site answers a submission with.
+/// Pages without one, such as being asked to wait before answering again,
+/// are errors quoting the page's message.
+pub fn parse_verdict(page: &str) -> Result<Verdict> {
+    if page.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if !page.contains("That's not the right answer") {
+        Err(format!("The site didn't judge the answer: {}", message(page)).into())
+    } else if page.contains("your answer is too high") {
+        Ok(Verdict::TooHigh)
+    } else if page.contains("your answer is too low") {
+        Ok(Verdict::TooLow)
+    } else {
+        Ok(Verdict::Incorrect)
+    }
+}
+
+/// The text of the page's `<article>`, where the site puts its message.
+fn message(page: &str) -> String {
+    let article = page
+        .split("<article>")
+        .nth(1)
+        .and_then(|rest| rest.split("</article>").next())
+        .unwrap_or(page);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn describe(url: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::Status(status, response) => {
+            let body = response.into_string().unwrap_or_default();
+            format!("{} answered {}: {}", url, status, body.trim())
+        }
+        ureq::Error::Transport(error) => format!("Could not reach the site: {}", error),
+    }
+}
+
+/// Make sure `day`'s input is at `path`, downloading it only when it isn't
+/// there yet. Returns whether it was downloaded.
+pub fn fetch(client: &Client, day: &Day, path: &Path) -> Result<bool> {
+    if path.exists() {
+        return Ok(false);
+    }
+    let input = client.input(day.number)?;
+    // Written beside the input and moved into place, so an interrupted
+    // write never leaves a partial input that looks cached.
+    let partial = path.with_extension("partial");
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&partial, input))
+        .and_then(|_| fs::rename(&partial, path));
+    written.map_err(|error| format!("Could not write {}: {}", path.display(), error))?;
+    Ok(true)
+}
+
+/// Where `aoc submit` keeps its history unless `AOC_HISTORY` says otherwise.
+pub const HISTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../submissions.json");
+
+/// One answer sent to the site and its verdict.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted so far, kept in a JSON file so that answers
+/// already known to be wrong are never sent again.
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Load the history at `path`, which is empty until the first answer is
+    /// recorded.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<History> {
+        let path = path.as_ref().to_path_buf();
+        let submissions = match fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|error| format!("Could not read {}: {}", path.display(), error))?,
+            Err(ref error) if error.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(error) => {
+                return Err(format!("Could not read {}: {}", path.display(), error).into())
+            }
+        };
+        Ok(History { path, submissions })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// What is already known about `answer` to `part` of day `number`: its
+    /// own verdict, or that it is wrong because another answer was right or
+    /// because it is past an answer that was too high or too low.
+    pub fn known(&self, number: u32, part: Part, answer: &str) -> Option<Verdict> {
+        let level = level(part);
+        let mut known = None;
+        for submission in &self.submissions {
+            if submission.day != number || submission.part != level {
+                continue;
+            }
+            if submission.answer == answer {
+                return Some(submission.verdict);
+            }
+            let beyond = match (answer.parse::<i64>(), submission.answer.parse::<i64>()) {
+                (Ok(answer), Ok(submitted)) => match submission.verdict {
+                    Verdict::TooHigh => answer > submitted,
+                    Verdict::TooLow => answer < submitted,
+                    _ => false,
+                },
+                _ => false,
+            };
+            if beyond {
+                known = Some(submission.verdict);
+            } else if submission.verdict == Verdict::Correct {
+                known = known.or(Some(Verdict::Incorrect));
+            }
+        }
+        known
+    }
+
+    /// Add a submission and save the history.
+    pub fn record(&mut self, submission: Submission) -> Result<()> {
+        self.submissions.push(submission);
+        let mut json = serde_json::to_string_pretty(&self.submissions).unwrap();
+        json.push('\n');
+        fs::write(&self.path, json)
+            .map_err(|error| format!("Could not write {}: {}", self.path.display(), error).into())
+    }
+}
+
+fn level(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// Submit `answer` to `part` of `day` unless the history already knows its
+/// verdict, and record the site's verdict when it doesn't. Answers known to
+/// be wrong are refused rather than sent again.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    day: &Day,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict> {
+    match history.known(day.number, part, answer) {
+        Some(Verdict::Correct) => return Ok(Verdict::Correct),
+        Some(verdict) => {
+            return Err(format!(
+                "Not submitting {} for day {} part {}, it is already known to be {}",
+                answer, day.number, part, verdict
+            )
+            .into())
+        }
+        None => (),
+    }
+    let verdict = client.submit(day.number, part, answer)?;
+    history.record(Submission {
+        day: day.number,
+        part: level(part),
+        answer: answer.to_string(),
+        verdict,
+    })?;
+    Ok(verdict)
+}
+
+#[test]
+fn test_parse_verdict() {
+    let page = |message: &str| {
+        format!(
+            "<html><main>\n<article><p>{}</p></article>\n</main></html>",
+            message
+        )
+    };
+    assert_eq!(
+        Verdict::Correct,
+        parse_verdict(&page(
+            "That's the right answer! You are one gold star closer."
+        ))
+        .unwrap()
+    );
+    assert_eq!(
+        Verdict::TooHigh,
+        parse_verdict(&page(
+            "That's not the right answer; your answer is too high."
+        ))
+        .unwrap()
+    );
+    assert_eq!(
+        Verdict::TooLow,
+        parse_verdict(&page(
+            "That's not the right answer; your answer is too low."
+        ))
+        .unwrap()
+    );
+    assert_eq!(
+        Verdict::Incorrect,
+        parse_verdict(&page("That's not the right answer. If you're stuck...")).unwrap()
+    );
+    assert_eq!(
+        "The site didn't judge the answer: You gave an answer too recently. You have 42s left to wait.",
+        parse_verdict(&page(
+            "You gave an answer too recently.  <span>You have 42s left to wait.</span>"
+        ))
+        .unwrap_err()
+        .to_string()
+    );
+}
+
+#[test]
+fn test_known_verdicts() {
+    let submission = |part, answer: &str, verdict| Submission {
+        day: 1,
+        part,
+        answer: answer.to_string(),
+        verdict,
+    };
+    let history = History {
+        path: PathBuf::new(),
+        submissions: vec![
+            submission(1, "100", Verdict::TooHigh),
+            submission(1, "10", Verdict::TooLow),
+            submission(1, "50", Verdict::Incorrect),
+            submission(2, "7", Verdict::Correct),
+        ],
+    };
+    assert_eq!(Some(Verdict::TooHigh), history.known(1, Part::One, "100"));
+    assert_eq!(Some(Verdict::TooHigh), history.known(1, Part::One, "101"));
+    assert_eq!(Some(Verdict::TooLow), history.known(1, Part::One, "-3"));
+    assert_eq!(Some(Verdict::Incorrect), history.known(1, Part::One, "50"));
+    assert_eq!(None, history.known(1, Part::One, "51"));
+    assert_eq!(None, history.known(1, Part::One, "abc"));
+    assert_eq!(Some(Verdict::Correct), history.known(1, Part::Two, "7"));
+    assert_eq!(Some(Verdict::Incorrect), history.known(1, Part::Two, "8"));
+    assert_eq!(None, history.known(2, Part::One, "100"));
+}
@@ -0,0 +1,556 @@
+//! The season runner: every day's solution registered behind the shared
+//! [`Day`] interface, plus the table and JSON the `aoc` binary prints, the
+//! example tests it generates and the fuzzing seed corpus.
+
+pub mod client;
+pub mod scaffold;
+pub mod server;
+
+use aoc_common::{image, open_input, read_input, Day, Part, Result};
+use serde::Serialize;
+use std::any::Any;
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DAYS: [Day; 8] = [
+    problem1::DAY,
+    problem_2::DAY,
+    problem_3::DAY,
+    problem_4::DAY,
+    day_5::DAY,
+    day_6::DAY,
+    day_7::DAY,
+    day_8::DAY,
+];
+
+/// Look a day up by the number given on the command line.
+pub fn find_day(number: &str) -> Result<&'static Day> {
+    DAYS.iter()
+        .find(|day| day.number.to_string() == number)
+        .ok_or_else(|| format!("There is no solution for day {}", number).into())
+}
+
+/// The outcome of running one part of one day.
+#[derive(Debug)]
+pub struct Answer {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Result<String>,
+    /// How long reading and parsing the input took, shared by every part of
+    /// the day.
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Run the given `parts` of `day` against the input at `input_path`, or
+/// the day's committed input when there is none. The input is parsed once,
+/// as it is read, and shared by every part.
+pub fn run(day: &Day, parts: &[Part], input_path: Option<&str>) -> Vec<Answer> {
+    let start = Instant::now();
+    let parsed = open_input(input_path.unwrap_or(day.input))
+        .and_then(|mut input| day.solution.parse_reader_any(&mut input));
+    let parse_time = start.elapsed();
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match parsed {
+                Ok(ref parsed) => day.solution.part_any(part, &**parsed),
+                Err(ref error) => Err(error.to_string().into()),
+            };
+            Answer {
+                day: day.number,
+                part,
+                answer,
+                parse_time,
+                solve_time: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+/// The picture `day` draws of the input at `input_path`, or of its
+/// committed input, with each cell `scale` pixels square. It is a PPM file
+/// when `ppm` is set and a PNG file otherwise.
+pub fn render(day: &Day, input_path: Option<&str>, ppm: bool, scale: usize) -> Result<Vec<u8>> {
+    let parsed = open_input(input_path.unwrap_or(day.input))
+        .and_then(|mut input| day.solution.parse_reader_any(&mut input))?;
+    let picture = day.solution.render_any(&*parsed)?;
+    if picture.width() == 0 || scale == 0 {
+        return Err("There is nothing to draw".into());
+    }
+    if ppm {
+        Ok(image::ppm(&picture, scale))
+    } else {
+        image::png(&picture, scale)
+    }
+}
+
+/// How running every part of one day ended.
+#[derive(Debug)]
+pub enum Outcome {
+    /// Every part ran, whether or not it found an answer.
+    Answered(Vec<Answer>),
+    /// The day panicked, with the panic's message.
+    Panicked(String),
+}
+
+impl Outcome {
+    /// The answers to show for `day`, where a panic is an error for every
+    /// part.
+    pub fn into_answers(self, day: &Day) -> Vec<Answer> {
+        match self {
+            Outcome::Answered(answers) => answers,
+            Outcome::Panicked(message) => Part::ALL
+                .iter()
+                .map(|&part| Answer {
+                    day: day.number,
+                    part,
+                    answer: Err(format!("panicked: {}", message).into()),
+                    parse_time: Duration::default(),
+                    solve_time: Duration::default(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Run every part of each of `days` against its committed input, sharing
+/// the days out between `threads` workers. The outcomes come back in the
+/// order of `days`, and a day that panics is reported without stopping the
+/// others.
+pub fn run_parallel(days: &[Day], threads: usize) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, days.len().max(1)) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let day = match days.get(i) {
+                        Some(day) => day,
+                        None => break,
+                    };
+                    // Solutions hold no state a panic could leave half
+                    // updated, so carrying on after one is safe.
+                    let run = panic::catch_unwind(AssertUnwindSafe(|| run(day, &Part::ALL, None)));
+                    let outcome = match run {
+                        Ok(answers) => Outcome::Answered(answers),
+                        Err(payload) => Outcome::Panicked(panic_message(&*payload)),
+                    };
+                    sender.send((i, outcome)).unwrap();
+                }
+            });
+        }
+    });
+    drop(sender);
+    let mut outcomes: Vec<(usize, Outcome)> = receiver.into_iter().collect();
+    outcomes.sort_by_key(|&(i, _)| i);
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "a panic without a message".to_string()
+    }
+}
+
+/// Which days passed, with an answer to every part, which failed to answer
+/// a part and which panicked.
+pub fn summary(days: &[Day], outcomes: &[Outcome]) -> String {
+    let mut passed = vec![];
+    let mut failed = vec![];
+    let mut panicked = vec![];
+    for (day, outcome) in days.iter().zip(outcomes) {
+        let number = day.number.to_string();
+        match outcome {
+            Outcome::Answered(answers) if answers.iter().all(|answer| answer.answer.is_ok()) => {
+                passed.push(number)
+            }
+            Outcome::Answered(_) => failed.push(number),
+            Outcome::Panicked(_) => panicked.push(number),
+        }
+    }
+    let list = |numbers: Vec<String>| {
+        if numbers.is_empty() {
+            "none".to_string()
+        } else {
+            numbers.join(" ")
+        }
+    };
+    format!(
+        "passed:   {}\nfailed:   {}\npanicked: {}\n",
+        list(passed),
+        list(failed),
+        list(panicked)
+    )
+}
+
+/// One answer as it appears in the JSON output. Exactly one of `answer` and
+/// `error` is set; the times are whole microseconds.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u32,
+    part: u8,
+    answer: Option<&'a str>,
+    error: Option<String>,
+    parse_time_us: u128,
+    solve_time_us: u128,
+}
+
+/// The JSON form of one answer, shared by `--format json` and `aoc serve`.
+fn record(answer: &Answer) -> Record<'_> {
+    Record {
+        day: answer.day,
+        part: match answer.part {
+            Part::One => 1,
+            Part::Two => 2,
+        },
+        answer: answer.answer.as_ref().ok().map(String::as_str),
+        error: answer.answer.as_ref().err().map(ToString::to_string),
+        parse_time_us: answer.parse_time.as_micros(),
+        solve_time_us: answer.solve_time.as_micros(),
+    }
+}
+
+/// Lay the answers out as a JSON array, one object per day and part in the
+/// order they were run, with the fields always in the same order.
+pub fn json(answers: &[Answer]) -> String {
+    let records: Vec<Record> = answers.iter().map(record).collect();
+    let mut json = serde_json::to_string_pretty(&records).unwrap();
+    json.push('\n');
+    json
+}
+
+/// Where `aoc examples` writes the tests generated from the directions.
+pub const EXAMPLE_TESTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/examples.rs");
+
+/// The source of an integration test file checking every example stated in
+/// the days' directions against `Day::run_example`.
+pub fn example_tests() -> Result<String> {
+    let mut tests = String::from(
+        "//! Generated by `aoc examples` from the examples in each day's directions.\n\
+         //! Regenerate it rather than editing it by hand.\n\
+         \n\
+         use aoc::find_day;\n\
+         use aoc_common::Part;\n\
+         \n\
+         fn check(day: &str, part: Part, input: &str, expected: &str) {\n\
+         \x20   let day = find_day(day).unwrap();\n\
+         \x20   assert_eq!(expected, day.run_example(part, input).unwrap());\n\
+         }\n",
+    );
+    for day in DAYS.iter() {
+        let mut names = vec![];
+        for example in day.examples()? {
+            let mut name = format!("{}_part_{}", day.name, example.part);
+            let repeats = names.iter().filter(|seen| **seen == name).count();
+            names.push(name.clone());
+            if repeats > 0 {
+                name = format!("{}_{}", name, repeats + 1);
+            }
+            let part = match example.part {
+                Part::One => "Part::One",
+                Part::Two => "Part::Two",
+            };
+            let args = [
+                format!("{:?}", day.number.to_string()),
+                part.to_string(),
+                format!("r#\"{}\"#", example.input),
+                format!("{:?}", example.answer),
+            ];
+            write!(tests, "\n#[test]\nfn {}() {{\n", name).unwrap();
+            // Laid out the way rustfmt would, which splits calls whose
+            // arguments run past 60 columns.
+            let args_line = args.join(", ");
+            if example.input.contains('\n') || args_line.len() > 60 {
+                tests.push_str("    check(\n");
+                for arg in &args {
+                    writeln!(tests, "        {},", arg).unwrap();
+                }
+                tests.push_str("    );\n");
+            } else {
+                writeln!(tests, "    check({});", args_line).unwrap();
+            }
+            tests.push_str("}\n");
+        }
+    }
+    Ok(tests)
+}
+
+/// Where `aoc corpus` writes the fuzzing seed corpus, one directory per
+/// fuzz target, which is named after the day.
+pub const FUZZ_CORPUS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../fuzz/corpus");
+
+/// Seed inputs for fuzzing `day`: its examples, then the start of its
+/// committed input cut into pieces small enough for the fuzzer.
+pub fn seed_corpus(day: &Day) -> Result<Vec<String>> {
+    const PIECE: usize = 1000;
+    const PIECES: usize = 8;
+    let mut corpus: Vec<String> = vec![];
+    for example in day.examples()? {
+        if !corpus.contains(&example.input) {
+            corpus.push(example.input);
+        }
+    }
+    let input = read_input(day.input)?;
+    // Cut between lines, or between numbers for a one line input, and
+    // anywhere at all when there is neither for a while.
+    let separator = if input.trim_end().contains('\n') {
+        '\n'
+    } else {
+        ' '
+    };
+    let mut piece = String::new();
+    for c in input.chars() {
+        piece.push(c);
+        if piece.len() >= PIECE && c == separator || piece.len() >= 4 * PIECE {
+            corpus.push(piece.split_off(0));
+            if corpus.len() >= PIECES {
+                return Ok(corpus);
+            }
+        }
+    }
+    if !piece.is_empty() {
+        corpus.push(piece);
+    }
+    Ok(corpus)
+}
+
+/// Lay the answers out as a table, one row per day and part.
+pub fn table(answers: &[Answer]) -> String {
+    let rows: Vec<(String, String, String)> = answers
+        .iter()
+        .map(|answer| {
+            let shown = match answer.answer {
+                Ok(ref value) => value.clone(),
+                Err(ref error) => format!("error: {}", error),
+            };
+            (answer.day.to_string(), answer.part.to_string(), shown)
+        })
+        .collect();
+    let header = ("Day".to_string(), "Part".to_string(), "Answer".to_string());
+    let day_width = rows
+        .iter()
+        .map(|row| row.0.len())
+        .fold(header.0.len(), usize::max);
+    let part_width = rows
+        .iter()
+        .map(|row| row.1.len())
+        .fold(header.1.len(), usize::max);
+    let answer_width = rows
+        .iter()
+        .map(|row| row.2.len())
+        .fold(header.2.len(), usize::max);
+
+    let mut table = String::new();
+    writeln!(
+        table,
+        "{:>dw$} | {:>pw$} | {}",
+        header.0,
+        header.1,
+        header.2,
+        dw = day_width,
+        pw = part_width
+    )
+    .unwrap();
+    writeln!(
+        table,
+        "{}-+-{}-+-{}",
+        "-".repeat(day_width),
+        "-".repeat(part_width),
+        "-".repeat(answer_width)
+    )
+    .unwrap();
+    for (day, part, answer) in rows {
+        writeln!(
+            table,
+            "{:>dw$} | {:>pw$} | {}",
+            day,
+            part,
+            answer,
+            dw = day_width,
+            pw = part_width
+        )
+        .unwrap();
+    }
+    table
+}
+
+#[test]
+fn test_days_are_registered_in_order() {
+    // `aoc new` appends days, so they stay in order as long as they are
+    // added in order.
+    let numbers: Vec<u32> = DAYS.iter().map(|day| day.number).collect();
+    assert_eq!((1..=8).collect::<Vec<u32>>(), numbers[..8]);
+    assert!(numbers.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(4, find_day("4").unwrap().number);
+    assert!(find_day("0").is_err());
+    assert!(find_day("four").is_err());
+}
+
+#[cfg(test)]
+struct Faulty;
+
+#[cfg(test)]
+impl aoc_common::Solution for Faulty {
+    type Parsed = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, _: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn part_1(&self, _: &()) -> Result<u32> {
+        Err("no answer".into())
+    }
+
+    fn part_2(&self, _: &()) -> Result<u32> {
+        let numbers: Vec<u32> = vec![];
+        Ok(numbers[0])
+    }
+}
+
+#[test]
+fn test_run_parallel() {
+    let failing = Day {
+        number: 20,
+        solution: &Faulty,
+        ..problem1::DAY
+    };
+    // Parsing an input that isn't there fails rather than panics.
+    let missing = Day {
+        number: 21,
+        input: "no such input",
+        ..problem1::DAY
+    };
+    let days = [DAYS[0], failing, DAYS[1], missing, DAYS[7]];
+    let outcomes = run_parallel(&days, 3);
+    assert_eq!(
+        "passed:   1 2 8\nfailed:   21\npanicked: 20\n",
+        summary(&days, &outcomes)
+    );
+    let answers: Vec<(u32, Part, bool)> = days
+        .iter()
+        .zip(outcomes)
+        .flat_map(|(day, outcome)| outcome.into_answers(day))
+        .map(|answer| (answer.day, answer.part, answer.answer.is_ok()))
+        .collect();
+    assert_eq!(
+        vec![
+            (1, Part::One, true),
+            (1, Part::Two, true),
+            (20, Part::One, false),
+            (20, Part::Two, false),
+            (2, Part::One, true),
+            (2, Part::Two, true),
+            (21, Part::One, false),
+            (21, Part::Two, false),
+            (8, Part::One, true),
+            (8, Part::Two, true),
+        ],
+        answers
+    );
+}
+
+#[test]
+fn test_render() {
+    let ppm = render(find_day("4").unwrap(), None, true, 2).unwrap();
+    let header = String::from_utf8_lossy(&ppm[..20]);
+    assert!(header.starts_with("P6\n120 "), "{:?}", header);
+    let png = render(find_day("6").unwrap(), None, false, 1).unwrap();
+    assert_eq!(b"\x89PNG", &png[..4]);
+    assert_eq!(
+        "There is no picture of this puzzle",
+        render(find_day("1").unwrap(), None, false, 1)
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[test]
+fn test_seed_corpus() {
+    for day in DAYS.iter() {
+        let corpus = seed_corpus(day).unwrap();
+        // A day just started may have neither examples nor input yet.
+        if day.solved_parts().unwrap().is_empty() {
+            continue;
+        }
+        assert!(corpus.len() > 1, "{} has no seeds", day.name);
+        // The largest input libFuzzer tries by default.
+        assert!(corpus.iter().all(|seed| seed.len() <= 4096));
+    }
+}
+
+#[test]
+fn test_table() {
+    let answers = vec![
+        Answer {
+            day: 1,
+            part: Part::One,
+            answer: Ok("587".into()),
+            parse_time: Duration::from_micros(1500),
+            solve_time: Duration::from_micros(20),
+        },
+        Answer {
+            day: 10,
+            part: Part::Two,
+            answer: Err("boom".into()),
+            parse_time: Duration::from_micros(1500),
+            solve_time: Duration::from_nanos(900),
+        },
+    ];
+    assert_eq!(
+        concat!(
+            "Day | Part | Answer\n",
+            "----+------+------------\n",
+            "  1 |    1 | 587\n",
+            " 10 |    2 | error: boom\n",
+        ),
+        table(&answers)
+    );
+    assert_eq!(
+        concat!(
+            "[\n",
+            "  {\n",
+            "    \"day\": 1,\n",
+            "    \"part\": 1,\n",
+            "    \"answer\": \"587\",\n",
+            "    \"error\": null,\n",
+            "    \"parse_time_us\": 1500,\n",
+            "    \"solve_time_us\": 20\n",
+            "  },\n",
+            "  {\n",
+            "    \"day\": 10,\n",
+            "    \"part\": 2,\n",
+            "    \"answer\": null,\n",
+            "    \"error\": \"boom\",\n",
+            "    \"parse_time_us\": 1500,\n",
+            "    \"solve_time_us\": 0\n",
+            "  }\n",
+            "]\n",
+        ),
+        json(&answers)
+    );
+}
+
+#[test]
+fn test_example_tests_are_up_to_date() {
+    assert_eq!(
+        example_tests().unwrap(),
+        read_input(EXAMPLE_TESTS).unwrap(),
+        "run `cargo run -p aoc -- examples` to regenerate {}",
+        EXAMPLE_TESTS
+    );
+}
@@ -0,0 +1,195 @@
+use aoc::client::{fetch, submit, Client, History, Verdict, HISTORY};
+use aoc::scaffold::{new_day, WORKSPACE};
+use aoc::server::{bind, serve};
+use aoc::{
+    example_tests, find_day, json, render, run, run_parallel, seed_corpus, summary, table, Answer,
+    DAYS, EXAMPLE_TESTS, FUZZ_CORPUS,
+};
+use aoc_common::{Day, Part, Result};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::thread;
+
+const USAGE: &str = "usage: aoc run <day> [part] [--input <path|->] [--format <table|json>]
+       aoc run all [--threads <n>] [--format <table|json>]
+       aoc examples
+       aoc generate <day> <size> [--seed <n>]
+       aoc corpus [dir]
+       aoc serve [address]
+       aoc fetch <day> [--input <path>]
+       aoc submit <day> <part> [--input <path|->]
+       aoc new <day>
+       aoc render <day> <file.png|file.ppm> [--input <path|->] [--scale <n>]";
+
+fn main() -> Result<()> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let input_path = take_option(&mut args, "--input");
+    let format = take_option(&mut args, "--format");
+    let seed = take_option(&mut args, "--seed");
+    let threads = take_option(&mut args, "--threads");
+    let scale = take_option(&mut args, "--scale");
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["run", days @ ..] => run_days(
+            days,
+            input_path.as_deref(),
+            format.as_deref(),
+            threads.as_deref(),
+        ),
+        ["examples"] => {
+            fs::write(EXAMPLE_TESTS, example_tests()?)
+                .map_err(|error| format!("Could not write {}: {}", EXAMPLE_TESTS, error))?;
+            println!("Wrote {}", EXAMPLE_TESTS);
+            Ok(())
+        }
+        ["generate", day, size] => {
+            let (seed, size) = match (seed.as_deref().unwrap_or("0").parse(), size.parse()) {
+                (Ok(seed), Ok(size)) => (seed, size),
+                _ => usage(),
+            };
+            print!("{}", (find_day(day)?.generate)(seed, size));
+            Ok(())
+        }
+        ["corpus"] => write_corpus(Path::new(FUZZ_CORPUS)),
+        ["corpus", dir] => write_corpus(Path::new(dir)),
+        ["fetch", day] => {
+            let day = find_day(day)?;
+            let path = input_path.as_deref().unwrap_or(day.input);
+            if fetch(&Client::from_env(), day, Path::new(path))? {
+                println!("Downloaded {}", path);
+            } else {
+                println!("Already have {}", path);
+            }
+            Ok(())
+        }
+        ["submit", day, part] => {
+            submit_answer(find_day(day)?, part.parse()?, input_path.as_deref())
+        }
+        ["new", day] => {
+            let number = day.parse().unwrap_or_else(|_| usage());
+            let workspace = Path::new(WORKSPACE)
+                .canonicalize()
+                .map_err(|error| format!("Could not find {}: {}", WORKSPACE, error))?;
+            let dir = new_day(&workspace, number)?;
+            println!("Created {}", dir.display());
+            println!(
+                "Paste the puzzle into its directions, then run `aoc fetch {}` and `aoc examples`",
+                number
+            );
+            Ok(())
+        }
+        ["render", day, output] => {
+            let scale = match scale.as_deref().unwrap_or("1").parse() {
+                Ok(scale) => scale,
+                Err(_) => usage(),
+            };
+            let ppm = output.ends_with(".ppm");
+            let picture = render(find_day(day)?, input_path.as_deref(), ppm, scale)?;
+            fs::write(output, picture)
+                .map_err(|error| format!("Could not write {}: {}", output, error))?;
+            println!("Wrote {}", output);
+            Ok(())
+        }
+        ["serve"] => start_server("127.0.0.1:3000"),
+        ["serve", address] => start_server(address),
+        _ => usage(),
+    }
+}
+
+/// Write every day's fuzzing seeds under `dir`, in a directory per target.
+fn write_corpus(dir: &Path) -> Result<()> {
+    for day in DAYS.iter() {
+        let seeds = seed_corpus(day)?;
+        let target = dir.join(day.name);
+        let written = fs::create_dir_all(&target).and_then(|_| {
+            seeds
+                .iter()
+                .enumerate()
+                .try_for_each(|(i, seed)| fs::write(target.join(format!("seed_{}", i)), seed))
+        });
+        written.map_err(|error| format!("Could not write {}: {}", target.display(), error))?;
+    }
+    println!("Wrote {}", dir.display());
+    Ok(())
+}
+
+/// Work out the answer and send it, unless the history already knows it to
+/// be wrong, exiting with 1 when it isn't right.
+fn submit_answer(day: &Day, part: Part, input_path: Option<&str>) -> Result<()> {
+    let answer = run(day, &[part], input_path).remove(0).answer?;
+    let history_path = env::var("AOC_HISTORY").unwrap_or_else(|_| HISTORY.to_string());
+    let mut history = History::load(history_path)?;
+    let verdict = submit(&Client::from_env(), &mut history, day, part, &answer)?;
+    println!(
+        "Day {} part {}: {} is {}",
+        day.number, part, answer, verdict
+    );
+    if verdict != Verdict::Correct {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+fn start_server(address: &str) -> Result<()> {
+    let server = bind(address)?;
+    println!("Listening on http://{}", server.server_addr());
+    serve(server);
+    Ok(())
+}
+
+fn run_days(
+    args: &[&str],
+    input_path: Option<&str>,
+    format: Option<&str>,
+    threads: Option<&str>,
+) -> Result<()> {
+    let format = match format {
+        None | Some("table") => table,
+        Some("json") => json,
+        Some(_) => usage(),
+    };
+    let mut days_summary = None;
+    let answers = match args {
+        ["all"] if input_path.is_none() => {
+            let threads = match threads {
+                Some(threads) => threads.parse().unwrap_or_else(|_| usage()),
+                None => thread::available_parallelism().map_or(1, usize::from),
+            };
+            let outcomes = run_parallel(&DAYS, threads);
+            days_summary = Some(summary(&DAYS, &outcomes));
+            DAYS.iter()
+                .zip(outcomes)
+                .flat_map(|(day, outcome)| outcome.into_answers(day))
+                .collect()
+        }
+        [day] => run(find_day(day)?, &Part::ALL, input_path),
+        [day, part] => run(find_day(day)?, &[part.parse()?], input_path),
+        _ => usage(),
+    };
+
+    print!("{}", format(&answers));
+    // On stderr, so the JSON on stdout stays just the answers.
+    if let Some(days_summary) = days_summary {
+        eprint!("{}", days_summary);
+    }
+    if answers.iter().any(|answer: &Answer| answer.answer.is_err()) {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+/// Remove `--name <value>` from `args`, returning the value.
+fn take_option(args: &mut Vec<String>, name: &str) -> Option<String> {
+    match args.iter().position(|arg| arg == name) {
+        Some(i) if i + 1 < args.len() => Some(args.drain(i..=i + 1).nth(1).unwrap()),
+        Some(_) => usage(),
+        None => None,
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
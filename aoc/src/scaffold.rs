@@ -0,0 +1,277 @@
+//! `aoc new`: a new day's crate, already implementing [`Solution`] and
+//! registered with the workspace and the runner, ready for the puzzle to be
+//! filled in.
+//!
+//! [`Solution`]: aoc_common::Solution
+
+use aoc_common::{read_input, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The workspace `aoc new` adds days to.
+pub const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const CARGO_TOML: &str = r#"[package]
+name = "{name}"
+version = "0.1.0"
+{authors}edition = "2018"
+
+[dependencies]
+aoc_common = { path = "../aoc_common" }
+"#;
+
+const LIB_RS: &str = r#"use aoc_common::generate::{seeded, Rng};
+use aoc_common::{parse_lines_from, parse_lines_with, Day, Result, Solution};
+use std::io::BufRead;
+
+pub const DAY: Day = Day {
+    number: {number},
+    name: "{name}",
+    input: concat!(env!("CARGO_MANIFEST_DIR"), "/input"),
+    answers: concat!(env!("CARGO_MANIFEST_DIR"), "/answers"),
+    solution: &{solution},
+    directions: concat!(env!("CARGO_MANIFEST_DIR"), "/directions"),
+    example: &{solution},
+    generate,
+};
+
+pub struct {solution};
+
+impl Solution for {solution} {
+    type Parsed = Vec<String>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        parse_lines_with(input, |line| Ok(line.to_string()))
+    }
+
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<Vec<String>> {
+        parse_lines_from(reader, |line| Ok(line.to_string())).collect()
+    }
+
+    fn part_1(&self, lines: &Vec<String>) -> Result<String> {
+        Err(format!("Part 1 is not solved yet, {} lines read", lines.len()).into())
+    }
+
+    fn part_2(&self, lines: &Vec<String>) -> Result<String> {
+        Err(format!("Part 2 is not solved yet, {} lines read", lines.len()).into())
+    }
+}
+
+#[test]
+#[ignore = "fill in the example from the directions"]
+fn test_part_1() {
+    let lines = {solution}.parse("").unwrap();
+    assert_eq!("", {solution}.part_1(&lines).unwrap());
+}
+
+/// A synthetic input of `size` lines.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut random = seeded(seed);
+    (0..size)
+        .map(|_| format!("{}\n", random.random_range(0..1000)))
+        .collect()
+}
+"#;
+
+const MAIN_RS: &str = r#"use aoc_common::{input_path_arg, open_input, Result, Solution};
+use {name}::{{solution}, DAY};
+
+fn main() -> Result<()> {
+    let mut input = open_input(input_path_arg(DAY.input))?;
+    let lines = {solution}.parse_reader(&mut input)?;
+    println!("Part 1 {}", {solution}.part_1(&lines)?);
+    println!("Part 2 {}", {solution}.part_2(&lines)?);
+    Ok(())
+}
+"#;
+
+const DIRECTIONS: &str = "--- Day {number} ---
+
+Paste the puzzle here, examples and all. `aoc examples` turns the examples
+it finds into tests.
+";
+
+/// Create the crate for day `number` in `workspace` and register it: as a
+/// workspace member, as a dependency of `aoc` and in [`DAYS`]. A number
+/// some day in [`DAYS`] already has is refused, whatever that day's crate is
+/// called. The crate starts with empty `input` and `answers` files, and
+/// the checks over every day leave it out until `answers` has a part.
+/// Nothing is written unless all three can be. Returns the new crate's
+/// directory.
+///
+/// [`DAYS`]: crate::DAYS
+pub fn new_day(workspace: &Path, number: u32) -> Result<PathBuf> {
+    if let Some(day) = crate::DAYS.iter().find(|day| day.number == number) {
+        return Err(format!("Day {} is already {}", number, day.name).into());
+    }
+    let name = format!("day_{}", number);
+    let dir = workspace.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    let members_path = workspace.join("Cargo.toml");
+    let members = insert_before(
+        &read_input(&members_path)?,
+        "    \"aoc\",\n",
+        &format!("    \"{}\",\n", name),
+    )
+    .ok_or_else(|| format!("{} has no \"aoc\" member", members_path.display()))?;
+
+    let manifest_path = workspace.join("aoc/Cargo.toml");
+    let manifest = read_input(&manifest_path)?;
+    let manifest = insert_dependency(&manifest, &name)
+        .ok_or_else(|| format!("{} has no path dependencies", manifest_path.display()))?;
+
+    let registry_path = workspace.join("aoc/src/lib.rs");
+    let registry = read_input(&registry_path)?;
+    if registry.contains(&format!("{}::DAY", name)) {
+        return Err(format!("{} is already in {}", name, registry_path.display()).into());
+    }
+    let registry = register(&registry, &name)
+        .ok_or_else(|| format!("{} has no DAYS array", registry_path.display()))?;
+
+    let authors = manifest
+        .lines()
+        .find(|line| line.starts_with("authors"))
+        .map_or(String::new(), |line| format!("{}\n", line));
+    let fill = |template: &str| {
+        template
+            .replace("{name}", &name)
+            .replace("{number}", &number.to_string())
+            .replace("{solution}", &format!("Day{}", number))
+            .replace("{authors}", &authors)
+    };
+    let files = [
+        (dir.join("Cargo.toml"), fill(CARGO_TOML)),
+        (dir.join("src/lib.rs"), fill(LIB_RS)),
+        (dir.join("src/main.rs"), fill(MAIN_RS)),
+        (dir.join("directions"), fill(DIRECTIONS)),
+        (dir.join("input"), String::new()),
+        (dir.join("answers"), String::new()),
+        (members_path, members),
+        (manifest_path, manifest),
+        (registry_path, registry),
+    ];
+    for (path, contents) in files.iter() {
+        let written = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(path, contents));
+        written.map_err(|error| format!("Could not write {}: {}", path.display(), error))?;
+    }
+    Ok(dir)
+}
+
+fn insert_before(text: &str, marker: &str, insert: &str) -> Option<String> {
+    let at = text.find(marker)?;
+    Some(format!("{}{}{}", &text[..at], insert, &text[at..]))
+}
+
+/// Add a path dependency on `name` after the last one.
+fn insert_dependency(manifest: &str, name: &str) -> Option<String> {
+    let last = manifest
+        .lines()
+        .rev()
+        .find(|line| line.contains("{ path = \"../"))?;
+    let at = manifest.find(last)? + last.len() + 1;
+    Some(format!(
+        "{}{} = {{ path = \"../{}\" }}\n{}",
+        &manifest[..at],
+        name,
+        name,
+        &manifest[at..]
+    ))
+}
+
+/// Append `name`'s day to the end of `DAYS`, one longer than before.
+fn register(registry: &str, name: &str) -> Option<String> {
+    const DAYS: &str = "pub const DAYS: [Day; ";
+    let start = registry.find(DAYS)? + DAYS.len();
+    let length_end = start + registry[start..].find(']')?;
+    let length: usize = registry[start..length_end].parse().ok()?;
+    let end = length_end + registry[length_end..].find("\n];")? + 1;
+    Some(format!(
+        "{}{}{}    {}::DAY,\n{}",
+        &registry[..start],
+        length + 1,
+        &registry[length_end..end],
+        name,
+        &registry[end..]
+    ))
+}
+
+#[test]
+fn test_new_day() {
+    let workspace = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+    fs::create_dir_all(workspace.join("aoc/src")).unwrap();
+    for file in &["Cargo.toml", "aoc/Cargo.toml", "aoc/src/lib.rs"] {
+        fs::copy(Path::new(WORKSPACE).join(file), workspace.join(file)).unwrap();
+    }
+
+    let last = crate::DAYS.last().unwrap();
+    let number = last.number + 1;
+    let dir = new_day(&workspace, number).unwrap();
+    let read = |file: &str| read_input(workspace.join(file)).unwrap();
+    let name = format!("day_{}", number);
+    assert!(read("Cargo.toml").contains(&format!(
+        "    \"{}\",\n    \"{}\",\n    \"aoc\",\n",
+        last.name, name
+    )));
+    assert!(read("aoc/Cargo.toml").contains(&format!(
+        "{0} = {{ path = \"../{0}\" }}\n{1} = {{ path = \"../{1}\" }}\n",
+        last.name, name
+    )));
+    let registry = read("aoc/src/lib.rs");
+    assert!(registry.contains(&format!("pub const DAYS: [Day; {}] = [", number)));
+    assert!(registry.contains(&format!("    {}::DAY,\n    {}::DAY,\n];", last.name, name)));
+    let lib = read(&format!("{}/src/lib.rs", name));
+    assert!(lib.contains(&format!(
+        "    number: {},\n    name: \"{}\",\n",
+        number, name
+    )));
+    assert!(lib.contains(&format!("impl Solution for Day{} {{", number)));
+    assert!(dir.join("src/main.rs").exists());
+    assert!(dir.join("directions").exists());
+    assert!(read(&format!("{}/src/main.rs", name)).contains("parse_reader(&mut input)"));
+
+    // Until its parts are solved the new day is left out of the suites run
+    // over every day, rather than failing them.
+    let path = |file: &str| -> &'static str {
+        Box::leak(dir.join(file).display().to_string().into_boxed_str())
+    };
+    let scaffolded = aoc_common::Day {
+        number,
+        name: "scaffolded",
+        input: path("input"),
+        answers: path("answers"),
+        directions: path("directions"),
+        ..*last
+    };
+    assert!(crate::seed_corpus(&scaffolded).unwrap().is_empty());
+    assert!(scaffolded.solved_parts().unwrap().is_empty());
+
+    assert!(new_day(&workspace, number).is_err());
+    // A day missing its directory but still registered is refused too,
+    // before anything is written.
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(new_day(&workspace, number).is_err());
+    assert!(!dir.exists());
+    // Days named before `day_<number>` was the convention are still taken.
+    for day in &[1, 3] {
+        assert_eq!(
+            format!(
+                "Day {} is already {}",
+                day,
+                crate::DAYS[*day as usize - 1].name
+            ),
+            new_day(&workspace, *day).unwrap_err().to_string()
+        );
+        assert!(!workspace.join(format!("day_{}", day)).exists());
+    }
+    assert!(!read("Cargo.toml").contains("\"day_1\""));
+
+    fs::remove_dir_all(workspace).unwrap();
+}
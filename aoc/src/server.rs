@@ -0,0 +1,196 @@
+//! `aoc serve`: every solver behind `POST /day/{n}/part/{p}`, answering the
+//! puzzle text in the request body with the same JSON object per answer as
+//! `aoc run --format json`.
+
+use crate::{find_day, record, Answer};
+use aoc_common::{Error, Part, Result};
+use std::io::{self, BufRead, BufReader, Read};
+use std::thread;
+use std::time::Instant;
+use tiny_http::{Header, Request, Response, Server};
+
+/// The most of a request body that is read, far more than any puzzle input.
+/// Longer bodies are refused with a 413.
+pub const MAX_BODY: u64 = 8 << 20;
+
+/// Listen on `address`, such as `127.0.0.1:3000`, or port 0 for any free
+/// port.
+pub fn bind(address: &str) -> Result<Server> {
+    Server::http(address)
+        .map_err(|error| format!("Could not listen on {}: {}", address, error).into())
+}
+
+/// Answer requests until the server is dropped, each on its own thread so
+/// one slow solve doesn't hold up the rest.
+pub fn serve(server: Server) {
+    for request in server.incoming_requests() {
+        thread::spawn(move || handle(request));
+    }
+}
+
+fn handle(mut request: Request) {
+    let method = request.method().to_string();
+    let url = request.url().to_string();
+    // A body that says up front it is too long isn't read at all.
+    let (status, body) = if request
+        .body_length()
+        .is_some_and(|length| length as u64 > MAX_BODY)
+    {
+        error_response(413, &too_long())
+    } else {
+        respond(&method, &url, &mut BufReader::new(request.as_reader()))
+    };
+    let content_type = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap();
+    let response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(content_type);
+    // The client hanging up before the answer is ready is no concern of ours.
+    let _ = request.respond(response);
+}
+
+/// The status and JSON body answering `method url` with the puzzle text in
+/// `body`. Input that doesn't parse is the client's mistake, a 400, and so
+/// is input that parses but has no answer, a 422. Only [`MAX_BODY`] bytes of
+/// `body` are read, and a longer one is a 413.
+pub fn respond(method: &str, url: &str, body: &mut dyn BufRead) -> (u16, String) {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').collect();
+    let (day, part) = match segments.as_slice() {
+        ["", "day", day, "part", part] => match (find_day(day), part.parse::<Part>()) {
+            (Ok(day), Ok(part)) => (day, part),
+            (Err(error), _) | (_, Err(error)) => return error_response(404, &error),
+        },
+        _ => return error_response(404, &format!("No such page {}", path).into()),
+    };
+    if method != "POST" {
+        return error_response(405, &"Post the puzzle input to solve it".into());
+    }
+
+    let start = Instant::now();
+    let mut limited = Limited {
+        body,
+        left: MAX_BODY,
+        exceeded: false,
+    };
+    let parsed = match day
+        .solution
+        .parse_reader_any(&mut BufReader::new(&mut limited))
+    {
+        Ok(parsed) => parsed,
+        Err(_) if limited.exceeded => return error_response(413, &too_long()),
+        Err(error) => return error_response(400, &error),
+    };
+    let parse_time = start.elapsed();
+    let start = Instant::now();
+    let answer = Answer {
+        day: day.number,
+        part,
+        answer: day.solution.part_any(part, &*parsed),
+        parse_time,
+        solve_time: start.elapsed(),
+    };
+    let status = if answer.answer.is_ok() { 200 } else { 422 };
+    (status, serde_json::to_string(&record(&answer)).unwrap())
+}
+
+fn too_long() -> Error {
+    format!("The puzzle input can be at most {} bytes", MAX_BODY).into()
+}
+
+/// A body that fails to read once it goes past `left` bytes, so that a body
+/// sent without its length can't take up unlimited memory either.
+struct Limited<'a> {
+    body: &'a mut dyn BufRead,
+    left: u64,
+    exceeded: bool,
+}
+
+impl Read for Limited<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // Reading one byte past the limit tells a body of exactly the limit
+        // from a longer one.
+        let most = buf.len().min(self.left.saturating_add(1) as usize);
+        let read = self.body.read(&mut buf[..most])?;
+        if read as u64 > self.left {
+            self.exceeded = true;
+            return Err(io::Error::other(too_long().to_string()));
+        }
+        self.left -= read as u64;
+        Ok(read)
+    }
+}
+
+fn error_response(status: u16, error: &Error) -> (u16, String) {
+    (
+        status,
+        serde_json::json!({ "error": error.to_string() }).to_string(),
+    )
+}
+
+#[cfg(test)]
+fn without_times(body: &str) -> serde_json::Value {
+    let mut body: serde_json::Value = serde_json::from_str(body).unwrap();
+    if let Some(body) = body.as_object_mut() {
+        body.remove("parse_time_us");
+        body.remove("solve_time_us");
+    }
+    body
+}
+
+#[test]
+fn test_respond() {
+    let (status, body) = respond("POST", "/day/1/part/1", &mut "+1\n-2\n+3\n+1\n".as_bytes());
+    assert_eq!(200, status);
+    assert_eq!(
+        serde_json::json!({ "day": 1, "part": 1, "answer": "3", "error": null }),
+        without_times(&body)
+    );
+
+    let (status, body) = respond(
+        "POST",
+        "/day/4/part/2?x=1",
+        &mut "[1518-11-01 00:00] Guard".as_bytes(),
+    );
+    assert_eq!(400, status);
+    assert!(without_times(&body)["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Could not parse the input at line 1"));
+
+    let cycle = "Step A must be finished before step B can begin.\n\
+                 Step B must be finished before step A can begin.\n";
+    let (status, body) = respond("POST", "/day/7/part/1", &mut cycle.as_bytes());
+    assert_eq!(422, status);
+    assert_eq!(
+        "The steps AB all wait on each other",
+        without_times(&body)["error"]
+    );
+
+    let huge = "a".repeat(MAX_BODY as usize + 1);
+    assert_eq!(
+        413,
+        respond("POST", "/day/5/part/1", &mut huge.as_bytes()).0
+    );
+
+    assert_eq!(404, respond("POST", "/day/0/part/1", &mut "".as_bytes()).0);
+    assert_eq!(404, respond("POST", "/day/1/part/3", &mut "".as_bytes()).0);
+    assert_eq!(404, respond("POST", "/day/1", &mut "".as_bytes()).0);
+    assert_eq!(405, respond("GET", "/day/1/part/1", &mut "".as_bytes()).0);
+}
+
+#[test]
+fn test_limited_body() {
+    let read = |body: &str, left| {
+        let mut limited = Limited {
+            body: &mut body.as_bytes(),
+            left,
+            exceeded: false,
+        };
+        let mut text = String::new();
+        let read = limited.read_to_string(&mut text).map(|_| text);
+        (read.ok(), limited.exceeded)
+    };
+    assert_eq!((Some("+1\n-2\n".to_string()), false), read("+1\n-2\n", 6));
+    assert_eq!((None, true), read("+1\n-2\n", 5));
+    assert_eq!((Some(String::new()), false), read("", 0));
+}
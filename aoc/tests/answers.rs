@@ -0,0 +1,60 @@
+//! Every day run against its committed input must still produce the answers
+//! recorded in its `answers` file.
+
+use aoc::{find_day, run};
+
+fn check(number: &str) {
+    let day = find_day(number).unwrap();
+    let expected = day.expected_answers().unwrap();
+    let parts: Vec<_> = expected.iter().map(|(part, _)| *part).collect();
+    let answers = run(day, &parts, None);
+    for ((part, expected), answer) in expected.iter().zip(answers) {
+        assert_eq!(
+            expected,
+            &answer.answer.unwrap(),
+            "{} part {} no longer gives its recorded answer",
+            day.name,
+            part
+        );
+    }
+}
+
+#[test]
+fn problem1() {
+    check("1");
+}
+
+#[test]
+fn problem_2() {
+    check("2");
+}
+
+#[test]
+fn problem_3() {
+    check("3");
+}
+
+#[test]
+fn problem_4() {
+    check("4");
+}
+
+#[test]
+fn day_5() {
+    check("5");
+}
+
+#[test]
+fn day_6() {
+    check("6");
+}
+
+#[test]
+fn day_7() {
+    check("7");
+}
+
+#[test]
+fn day_8() {
+    check("8");
+}
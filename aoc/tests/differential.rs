@@ -0,0 +1,211 @@
+//! The optimised solutions checked against deliberately naive versions of
+//! the same puzzles on random inputs. proptest shrinks any disagreement to
+//! a minimal input before reporting it.
+
+use proptest::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// problem_2 part 2: the letters the two closest ids have in common, found
+/// by comparing every pair.
+fn closest_common_letters(ids: &[String]) -> Option<String> {
+    let mut best: Option<(usize, String)> = None;
+    let mut tied = false;
+    for (i, a) in ids.iter().enumerate() {
+        for b in &ids[i + 1..] {
+            let common: String = a
+                .chars()
+                .zip(b.chars())
+                .filter(|(x, y)| x == y)
+                .map(|(x, _)| x)
+                .collect();
+            match best {
+                Some((length, _)) if length > common.len() => {}
+                Some((length, _)) if length == common.len() => tied = true,
+                _ => {
+                    tied = false;
+                    best = Some((common.len(), common));
+                }
+            }
+        }
+    }
+    if tied {
+        None
+    } else {
+        best.map(|(_, common)| common)
+    }
+}
+
+/// problem_3: how many claims cover each square inch.
+fn claimed(claims: &[(u32, u32, u32, u32)]) -> HashMap<(u32, u32), u32> {
+    let mut claimed = HashMap::new();
+    for &(x, y, width, height) in claims {
+        for i in x..x + width {
+            for j in y..y + height {
+                *claimed.entry((i, j)).or_insert(0) += 1;
+            }
+        }
+    }
+    claimed
+}
+
+fn claims_input(claims: &[(u32, u32, u32, u32)]) -> String {
+    claims
+        .iter()
+        .enumerate()
+        .map(|(i, (x, y, width, height))| {
+            format!("#{} @ {},{}: {}x{}\n", i + 1, x, y, width, height)
+        })
+        .collect()
+}
+
+/// day_5: react by removing the first reacting pair until none is left.
+fn react(polymer: &str) -> usize {
+    let mut units: Vec<char> = polymer.chars().collect();
+    while let Some(i) = (1..units.len())
+        .find(|&i| units[i] != units[i - 1] && units[i].eq_ignore_ascii_case(&units[i - 1]))
+    {
+        units.drain(i - 1..=i);
+    }
+    units.len()
+}
+
+fn distance(a: (i64, i64), b: (i64, i64)) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+/// day_6 part 1: every location in the bounding box given to its one
+/// closest coordinate, dropping the areas that reach the edge of the box
+/// since those go on forever.
+fn largest_finite_area(coords: &[(i64, i64)]) -> u32 {
+    let (min_x, max_x) = (
+        coords.iter().map(|c| c.0).min().unwrap(),
+        coords.iter().map(|c| c.0).max().unwrap(),
+    );
+    let (min_y, max_y) = (
+        coords.iter().map(|c| c.1).min().unwrap(),
+        coords.iter().map(|c| c.1).max().unwrap(),
+    );
+    let mut areas: HashMap<usize, u32> = HashMap::new();
+    let mut infinite = HashSet::new();
+    for x in min_x..=max_x {
+        for y in min_y..=max_y {
+            let distances: Vec<i64> = coords.iter().map(|&c| distance(c, (x, y))).collect();
+            let closest = *distances.iter().min().unwrap();
+            let mut owners = (0..coords.len()).filter(|&i| distances[i] == closest);
+            if let (Some(owner), None) = (owners.next(), owners.next()) {
+                *areas.entry(owner).or_insert(0) += 1;
+                if x == min_x || x == max_x || y == min_y || y == max_y {
+                    infinite.insert(owner);
+                }
+            }
+        }
+    }
+    areas
+        .iter()
+        .filter(|(owner, _)| !infinite.contains(owner))
+        .map(|(_, area)| *area)
+        .max()
+        .unwrap_or(0)
+}
+
+/// day_6 part 2: no location further than `max_distance` from the bounding
+/// box can be close enough in total, so checking that far out is enough.
+fn safe_region(coords: &[(i64, i64)], max_distance: u32) -> u32 {
+    let reach = i64::from(max_distance);
+    let (min_x, max_x) = (
+        coords.iter().map(|c| c.0).min().unwrap() - reach,
+        coords.iter().map(|c| c.0).max().unwrap() + reach,
+    );
+    let (min_y, max_y) = (
+        coords.iter().map(|c| c.1).min().unwrap() - reach,
+        coords.iter().map(|c| c.1).max().unwrap() + reach,
+    );
+    let mut count = 0;
+    for x in min_x..=max_x {
+        for y in min_y..=max_y {
+            let total: i64 = coords.iter().map(|&c| distance(c, (x, y))).sum();
+            if total < reach {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn coords_input(coords: &[(i64, i64)]) -> String {
+    coords
+        .iter()
+        .map(|(x, y)| format!("{}, {}\n", x, y))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn problem_2_part_2(
+        mut ids in prop::collection::vec("[a-z]{8}", 1..12),
+        twin in any::<prop::sample::Index>(),
+        position in 0..8usize,
+        letter in "[a-z]",
+    ) {
+        // The puzzle promises one pair of ids a letter apart.
+        let mut changed = ids[twin.index(ids.len())].clone().into_bytes();
+        changed[position] = letter.as_bytes()[0];
+        ids.push(String::from_utf8(changed).unwrap());
+        let expected = closest_common_letters(&ids);
+        prop_assume!(expected.is_some());
+        prop_assert_eq!(expected.unwrap(), problem_2::part_2(&ids));
+    }
+
+    #[test]
+    fn problem_3_part_1(claims in prop::collection::vec((0..20u32, 0..20u32, 0..8u32, 0..8u32), 0..12)) {
+        let parsed = problem_3::claims_from_input(&claims_input(&claims)).unwrap();
+        let overlapping = claimed(&claims).values().filter(|&&count| count >= 2).count();
+        prop_assert_eq!(overlapping as u32, problem_3::part_1(&parsed).unwrap());
+    }
+
+    #[test]
+    fn problem_3_part_2(claims in prop::collection::vec((0..20u32, 0..20u32, 0..8u32, 0..8u32), 1..12)) {
+        let parsed = problem_3::claims_from_input(&claims_input(&claims)).unwrap();
+        let claimed = claimed(&claims);
+        let intact: Vec<u32> = claims
+            .iter()
+            .enumerate()
+            .filter(|(_, &(x, y, width, height))| {
+                (x..x + width).all(|i| (y..y + height).all(|j| claimed[&(i, j)] == 1))
+            })
+            .map(|(i, _)| i as u32 + 1)
+            .collect();
+        match problem_3::part_2(&parsed) {
+            Ok(id) => prop_assert!(intact.contains(&id), "claim {} is not intact, {:?} are", id, intact),
+            Err(_) => prop_assert!(intact.is_empty(), "missed the intact claims {:?}", intact),
+        }
+    }
+
+    #[test]
+    fn day_5_part_2(polymer in "[a-dA-D]{0,40}") {
+        let expected = "abcdefghijklmnopqrstuvwxyz"
+            .chars()
+            .map(|remove| {
+                let kept: String = polymer.chars().filter(|c| !c.eq_ignore_ascii_case(&remove)).collect();
+                react(&kept)
+            })
+            .min()
+            .unwrap();
+        prop_assert_eq!(expected as u32, day_5::part_2(&polymer).unwrap());
+    }
+
+    #[test]
+    fn day_6_coords_most_area(coords in prop::collection::vec((0..30i64, 0..30i64), 1..8)) {
+        let parsed: Vec<day_6::Coord> = aoc_common::parse_lines(&coords_input(&coords)).unwrap();
+        prop_assert_eq!(largest_finite_area(&coords), day_6::coords_most_area(&parsed).unwrap());
+    }
+
+    #[test]
+    fn day_6_coords_sum(coords in prop::collection::vec((0..30i64, 0..30i64), 1..8), max_distance in 0..60u32) {
+        let parsed: Vec<day_6::Coord> = aoc_common::parse_lines(&coords_input(&coords)).unwrap();
+        prop_assert_eq!(
+            safe_region(&coords, max_distance),
+            day_6::coords_sum(&parsed, max_distance).unwrap()
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! Generated by `aoc examples` from the examples in each day's directions.
+//! Regenerate it rather than editing it by hand.
+
+use aoc::find_day;
+use aoc_common::Part;
+
+fn check(day: &str, part: Part, input: &str, expected: &str) {
+    let day = find_day(day).unwrap();
+    assert_eq!(expected, day.run_example(part, input).unwrap());
+}
+
+#[test]
+fn problem1_part_1() {
+    check("1", Part::One, r#"+1, -2, +3, +1"#, "3");
+}
+
+#[test]
+fn problem1_part_1_2() {
+    check("1", Part::One, r#"+1, +1, +1"#, "3");
+}
+
+#[test]
+fn problem1_part_1_3() {
+    check("1", Part::One, r#"+1, +1, -2"#, "0");
+}
+
+#[test]
+fn problem1_part_1_4() {
+    check("1", Part::One, r#"-1, -2, -3"#, "-6");
+}
+
+#[test]
+fn problem1_part_2() {
+    check("1", Part::Two, r#"+1, -2, +3, +1"#, "2");
+}
+
+#[test]
+fn problem1_part_2_2() {
+    check("1", Part::Two, r#"+1, -1"#, "0");
+}
+
+#[test]
+fn problem1_part_2_3() {
+    check("1", Part::Two, r#"+3, +3, +4, -2, -4"#, "10");
+}
+
+#[test]
+fn problem1_part_2_4() {
+    check("1", Part::Two, r#"-6, +3, +8, +5, -6"#, "5");
+}
+
+#[test]
+fn problem1_part_2_5() {
+    check("1", Part::Two, r#"+7, +7, -2, -7, -4"#, "14");
+}
+
+#[test]
+fn problem_2_part_1() {
+    check(
+        "2",
+        Part::One,
+        r#"abcdef
+bababc
+abbcde
+abcccd
+aabcdd
+abcdee
+ababab"#,
+        "12",
+    );
+}
+
+#[test]
+fn problem_2_part_2() {
+    check(
+        "2",
+        Part::Two,
+        r#"abcde
+fghij
+klmno
+pqrst
+fguij
+axcye
+wvxyz"#,
+        "fgij",
+    );
+}
+
+#[test]
+fn problem_3_part_2() {
+    check(
+        "3",
+        Part::Two,
+        r#"#1 @ 1,3: 4x4
+#2 @ 3,1: 4x4
+#3 @ 5,5: 2x2"#,
+        "3",
+    );
+}
+
+#[test]
+fn problem_4_part_1() {
+    check(
+        "4",
+        Part::One,
+        r#"[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up"#,
+        "240",
+    );
+}
+
+#[test]
+fn day_5_part_1() {
+    check("5", Part::One, r#"dabAcCaCBAcCcaDA"#, "10");
+}
+
+#[test]
+fn day_5_part_2() {
+    check("5", Part::Two, r#"dabAcCaCBAcCcaDA"#, "4");
+}
+
+#[test]
+fn day_6_part_1() {
+    check(
+        "6",
+        Part::One,
+        r#"1, 1
+1, 6
+8, 3
+3, 4
+5, 5
+8, 9"#,
+        "17",
+    );
+}
+
+#[test]
+fn day_7_part_1() {
+    check(
+        "7",
+        Part::One,
+        r#"Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin."#,
+        "CABDFE",
+    );
+}
+
+#[test]
+fn day_7_part_2() {
+    check(
+        "7",
+        Part::Two,
+        r#"Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin."#,
+        "15",
+    );
+}
+
+#[test]
+fn day_8_part_1() {
+    check(
+        "8",
+        Part::One,
+        r#"2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2"#,
+        "138",
+    );
+}
+
+#[test]
+fn day_8_part_2() {
+    check(
+        "8",
+        Part::Two,
+        r#"2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2"#,
+        "66",
+    );
+}
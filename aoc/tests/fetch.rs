@@ -0,0 +1,76 @@
+//! `aoc fetch` downloads an input once, from wherever it is pointed, and
+//! after that only ever reads it from disk.
+
+use aoc::client::{fetch, Client};
+use aoc::find_day;
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tiny_http::{Response, Server};
+
+/// The path and `Cookie` header of each request, in the order they came.
+type Requests = Arc<Mutex<Vec<(String, String)>>>;
+
+/// A stand-in for the site serving day 3's input and nothing else, which
+/// records the path and cookie of every request it gets.
+fn mock_site() -> (String, Requests) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr());
+    let requests = Arc::new(Mutex::new(vec![]));
+    let seen = Arc::clone(&requests);
+    thread::spawn(move || {
+        for request in server.incoming_requests() {
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Cookie"))
+                .map(|header| header.value.to_string())
+                .unwrap_or_default();
+            let url = request.url().to_string();
+            seen.lock().unwrap().push((url.clone(), cookie));
+            let response = if url == "/2018/day/3/input" {
+                Response::from_string("#1 @ 1,3: 4x4\n")
+            } else {
+                Response::from_string(
+                    "Please don't repeatedly request this endpoint before it unlocks!",
+                )
+                .with_status_code(404)
+            };
+            request.respond(response).unwrap();
+        }
+    });
+    (base_url, requests)
+}
+
+#[test]
+fn fetches_each_input_once() {
+    let (base_url, requests) = mock_site();
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    let path = dir.join("inputs/part_1");
+    let client = Client::new(&format!("{}/", base_url), Some("53551".to_string()));
+    let day = find_day("3").unwrap();
+
+    assert!(fetch(&client, day, &path).unwrap());
+    assert_eq!("#1 @ 1,3: 4x4\n", fs::read_to_string(&path).unwrap());
+    assert!(!fetch(&client, day, &path).unwrap());
+    assert_eq!(
+        vec![("/2018/day/3/input".to_string(), "session=53551".to_string())],
+        *requests.lock().unwrap()
+    );
+
+    let day_4 = find_day("4").unwrap();
+    let missing = dir.join("input");
+    let error = fetch(&client, day_4, &missing).unwrap_err().to_string();
+    assert!(error.ends_with(
+        "answered 404: Please don't repeatedly request this endpoint before it unlocks!"
+    ));
+    assert!(!missing.exists());
+    assert_eq!(2, requests.lock().unwrap().len());
+
+    let anonymous = Client::new(&base_url, None);
+    assert!(fetch(&anonymous, day_4, &missing).is_err());
+    assert!(fetch(&anonymous, day, &path).is_ok());
+    assert_eq!(2, requests.lock().unwrap().len());
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,40 @@
+//! Every day's generator must be repeatable for a seed and produce inputs
+//! its own solution can answer, for the parts it has solved.
+
+use aoc::DAYS;
+
+#[test]
+fn generators_are_repeatable() {
+    for day in DAYS.iter() {
+        assert_eq!(
+            (day.generate)(1, 40),
+            (day.generate)(1, 40),
+            "{} generated different inputs for the same seed",
+            day.name
+        );
+        assert_ne!(
+            (day.generate)(1, 40),
+            (day.generate)(2, 40),
+            "{} ignored the seed",
+            day.name
+        );
+    }
+}
+
+#[test]
+fn generated_inputs_are_solvable() {
+    for day in DAYS.iter() {
+        let solved = day.solved_parts().unwrap();
+        for seed in 0..5 {
+            let input = (day.generate)(seed, 60);
+            for &part in &solved {
+                if let Err(error) = day.run(part, &input) {
+                    panic!(
+                        "{} part {} failed on seed {}: {}\n{}",
+                        day.name, part, seed, error, input
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! `aoc serve` answers puzzles posted to it over HTTP.
+
+use aoc::server::{bind, serve};
+use std::thread;
+
+fn post(address: &str, path: &str, input: &str) -> (u16, serde_json::Value) {
+    let url = format!("http://{}{}", address, path);
+    let response = match ureq::post(&url).send_string(input) {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(error) => panic!("{} failed: {}", url, error),
+    };
+    assert_eq!("application/json", response.content_type());
+    let status = response.status();
+    let body = response.into_string().unwrap();
+    (status, serde_json::from_str(&body).unwrap())
+}
+
+#[test]
+fn serves_every_day_over_localhost() {
+    let server = bind("127.0.0.1:0").unwrap();
+    let address = server.server_addr().to_string();
+    thread::spawn(move || serve(server));
+
+    let (status, body) = post(&address, "/day/1/part/2", "+3\n+3\n+4\n-2\n-4\n");
+    assert_eq!(200, status);
+    assert_eq!(1, body["day"]);
+    assert_eq!(2, body["part"]);
+    assert_eq!("10", body["answer"]);
+
+    let (status, body) = post(
+        &address,
+        "/day/8/part/1",
+        "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2",
+    );
+    assert_eq!(200, status);
+    assert_eq!("138", body["answer"]);
+
+    let (status, body) = post(&address, "/day/6/part/1", "1, 1\n6 6\n");
+    assert_eq!(400, status);
+    assert_eq!(
+        "Could not parse the input at line 2, column 1: expected a coordinate like 1, 6, found \"6 6\"",
+        body["error"]
+    );
+
+    let (status, _) = post(&address, "/day/25/part/1", "");
+    assert_eq!(404, status);
+}
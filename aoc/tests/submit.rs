@@ -0,0 +1,95 @@
+//! `aoc submit` sends answers to the site, keeps what it said about each and
+//! never sends an answer it already knows is wrong.
+
+use aoc::client::{submit, Client, History, Verdict};
+use aoc::find_day;
+use aoc_common::Part;
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tiny_http::{Response, Server};
+
+/// A stand-in for the site whose only accepted answer is 587, which keeps
+/// the path and form of every submission it gets.
+fn stub_site() -> (String, Arc<Mutex<Vec<String>>>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr());
+    let submissions = Arc::new(Mutex::new(vec![]));
+    let seen = Arc::clone(&submissions);
+    thread::spawn(move || {
+        for mut request in server.incoming_requests() {
+            let mut form = String::new();
+            request.as_reader().read_to_string(&mut form).unwrap();
+            seen.lock()
+                .unwrap()
+                .push(format!("{} {}", request.url(), form));
+            let answer = form.split("answer=").nth(1).unwrap_or_default();
+            let message = match answer.parse::<i64>() {
+                Ok(587) => "That's the right answer! You are <em>one gold star</em> closer.",
+                Ok(answer) if answer > 587 => {
+                    "That's not the right answer; your answer is too high."
+                }
+                Ok(_) => "That's not the right answer; your answer is too low.",
+                Err(_) => "That's not the right answer.",
+            };
+            let page = format!(
+                "<html><body><main><article><p>{}</p></article></main></body></html>",
+                message
+            );
+            request.respond(Response::from_string(page)).unwrap();
+        }
+    });
+    (base_url, submissions)
+}
+
+#[test]
+fn submits_answers_not_known_to_be_wrong() {
+    let (base_url, submissions) = stub_site();
+    let path = std::env::temp_dir().join(format!("aoc-submissions-{}.json", std::process::id()));
+    let client = Client::new(&base_url, Some("53551".to_string()));
+    let day = find_day("1").unwrap();
+    let mut history = History::load(&path).unwrap();
+
+    let verdicts: Vec<_> = ["600", "500", "abc"]
+        .iter()
+        .map(|answer| submit(&client, &mut history, day, Part::One, answer).unwrap())
+        .collect();
+    assert_eq!(
+        vec![Verdict::TooHigh, Verdict::TooLow, Verdict::Incorrect],
+        verdicts
+    );
+
+    // All three are known to be wrong from the history, without asking.
+    let mut history = History::load(&path).unwrap();
+    for answer in &["600", "601", "499", "abc"] {
+        let error = submit(&client, &mut history, day, Part::One, answer).unwrap_err();
+        assert!(
+            error.to_string().contains("already known to be"),
+            "{}",
+            error
+        );
+    }
+    assert_eq!(3, submissions.lock().unwrap().len());
+
+    assert_eq!(
+        Verdict::Correct,
+        submit(&client, &mut history, day, Part::One, "587").unwrap()
+    );
+    assert_eq!(
+        Verdict::Correct,
+        submit(&client, &mut history, day, Part::One, "587").unwrap()
+    );
+    assert!(submit(&client, &mut history, day, Part::One, "588").is_err());
+    assert_eq!(
+        vec![
+            "/2018/day/1/answer level=1&answer=600",
+            "/2018/day/1/answer level=1&answer=500",
+            "/2018/day/1/answer level=1&answer=abc",
+            "/2018/day/1/answer level=1&answer=587",
+        ],
+        *submissions.lock().unwrap()
+    );
+    assert_eq!(4, History::load(&path).unwrap().submissions().len());
+
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,138 @@
+use crate::{examples, parse_lines_with, read_input, AnySolution, Example, ParseError, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// One of the two halves of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = crate::Error;
+
+    fn from_str(value: &str) -> Result<Part> {
+        match value {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Unknown part {:?}, expected 1 or 2", value).into()),
+        }
+    }
+}
+
+/// The entry point of a day's solution, so the runner can drive every day
+/// the same way.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u32,
+    pub name: &'static str,
+    /// The committed puzzle input for this day.
+    pub input: &'static str,
+    /// The accepted answers for `input`, one `part_<n>: <answer>` per line.
+    pub answers: &'static str,
+    pub solution: &'static dyn AnySolution,
+    /// The puzzle text, with the worked examples.
+    pub directions: &'static str,
+    /// The solution configured the way the examples are, which differs from
+    /// `solution` when the real puzzle uses bigger limits.
+    pub example: &'static dyn AnySolution,
+    /// A synthetic input of about `size` records, always the same for the
+    /// same seed.
+    pub generate: fn(seed: u64, size: usize) -> String,
+}
+
+impl Day {
+    /// Parse `input` and answer one `part` from it.
+    pub fn run(&self, part: Part, input: &str) -> Result<String> {
+        let parsed = self.solution.parse_any(input)?;
+        self.solution.part_any(part, &*parsed)
+    }
+
+    /// The accepted answers recorded in the day's `answers` file.
+    pub fn expected_answers(&self) -> Result<Vec<(Part, String)>> {
+        parse_answers(&read_input(self.answers)?)
+    }
+
+    /// The parts with an accepted answer recorded, which are the ones the
+    /// solution is expected to answer. None for a day still being solved.
+    pub fn solved_parts(&self) -> Result<Vec<Part>> {
+        Ok(self
+            .expected_answers()?
+            .into_iter()
+            .map(|(part, _)| part)
+            .collect())
+    }
+
+    /// The examples and their stated answers from the day's directions.
+    pub fn examples(&self) -> Result<Vec<Example>> {
+        Ok(examples(&read_input(self.directions)?))
+    }
+
+    /// Parse an example `input` and answer one `part` from it.
+    pub fn run_example(&self, part: Part, input: &str) -> Result<String> {
+        let parsed = self.example.parse_any(input)?;
+        self.example.part_any(part, &*parsed)
+    }
+}
+
+fn parse_answers(input: &str) -> Result<Vec<(Part, String)>> {
+    const EXPECTED: &str = "an answer like part_1: 587";
+    parse_lines_with(input, |line| {
+        let mut split = line.splitn(2, ": ");
+        let part = match split.next() {
+            Some("part_1") => Part::One,
+            Some("part_2") => Part::Two,
+            _ => return Err(ParseError::new(line, 1, EXPECTED)),
+        };
+        match split.next() {
+            Some(answer) => Ok((part, answer.to_string())),
+            None => Err(ParseError::new(line, 1, EXPECTED)),
+        }
+    })
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Day")
+            .field("number", &self.number)
+            .field("name", &self.name)
+            .field("input", &self.input)
+            .field("answers", &self.answers)
+            .field("directions", &self.directions)
+            .finish()
+    }
+}
+
+#[test]
+fn test_parse_part() {
+    assert_eq!(Part::One, "1".parse().unwrap());
+    assert_eq!(Part::Two, "2".parse().unwrap());
+    assert!("3".parse::<Part>().is_err());
+}
+
+#[test]
+fn test_parse_answers() {
+    assert_eq!(
+        vec![
+            (Part::One, "587".to_string()),
+            (Part::Two, "ab c".to_string())
+        ],
+        parse_answers("part_1: 587\npart_2: ab c\n").unwrap()
+    );
+    assert!(parse_answers("part_3: 1").is_err());
+    assert!(parse_answers("part_1 587").is_err());
+}
@@ -0,0 +1,361 @@
+//! Pulls the worked examples out of a day's puzzle directions: the example
+//! input and the answer the text states for it, so they can be checked
+//! without being copied by hand.
+
+use crate::Part;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// An example input and the answer the directions give for one part of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+enum Found {
+    Input(String),
+    Answer(String),
+    /// An input and its answer stated together, which doesn't replace the
+    /// example later answers refer back to.
+    Example(String, String),
+}
+
+/// Every example in `directions`. An answer is paired with the most recent
+/// example input, which may come from an earlier part ("using the same
+/// instructions as above").
+pub fn examples(directions: &str) -> Vec<Example> {
+    let mut examples = vec![];
+    let mut input: Option<String> = None;
+    for (part, text) in parts(directions) {
+        let mut found = inputs(text);
+        found.extend(answers(text));
+        found.extend(stated(text));
+        found.sort_by_key(|(position, _)| *position);
+        for (_, found) in found {
+            match found {
+                Found::Input(text) => input = Some(text),
+                Found::Answer(answer) => {
+                    if let Some(ref input) = input {
+                        examples.push(Example {
+                            part,
+                            input: input.clone(),
+                            answer,
+                        });
+                    }
+                }
+                Found::Example(input, answer) => examples.push(Example {
+                    part,
+                    input,
+                    answer,
+                }),
+            }
+        }
+    }
+    examples
+}
+
+fn parts(directions: &str) -> Vec<(Part, &str)> {
+    match directions.find("--- Part Two ---") {
+        Some(i) => vec![(Part::One, &directions[..i]), (Part::Two, &directions[i..])],
+        None => vec![(Part::One, directions)],
+    }
+}
+
+/// Example inputs, either a block of lines introduced by a line like "For
+/// example, consider the following claims:", or named inline as in "consider
+/// a larger example, dabAcCaCBAcCcaDA:" and "frequency changes of +1, -2, +3,
+/// +1, then". An indented list introduced the same way counts when each line
+/// starts with a different item, as in "    abcdef contains no letters that
+/// appear exactly two or three times."; the items are the input. Drawings
+/// ("Visually, these claim the following areas:") and indented prose are not
+/// inputs.
+fn inputs(text: &str) -> Vec<(usize, Found)> {
+    lazy_static! {
+        static ref INLINE: Vec<Regex> = [r"example, (\w+):", r"changes of ([+-]\d+(?:, [+-]\d+)*)"]
+            .iter()
+            .map(|phrase| Regex::new(phrase).unwrap())
+            .collect();
+    }
+    let mut found = vec![];
+    let mut offset = 0;
+    let mut introduced = false;
+    let mut block: Option<(usize, Vec<&str>)> = None;
+    let mut listed: Vec<(usize, &str)> = vec![];
+    for line in text.split('\n') {
+        let blank = line.trim().is_empty();
+        let indented = line.starts_with(char::is_whitespace);
+        if introduced {
+            if !blank && !indented && !line.contains("--") && listed.is_empty() {
+                block.get_or_insert((offset, vec![])).1.push(line);
+            } else if !blank && indented && block.is_none() {
+                let item = line.trim_start();
+                let item = item.split_whitespace().next().unwrap_or(item);
+                listed.push((offset + line.len() - line.trim_start().len(), item));
+            } else if block.is_some() || !listed.is_empty() || !blank {
+                introduced = false;
+            }
+        }
+        if !introduced {
+            if let Some((start, lines)) = block.take() {
+                found.push((start, Found::Input(lines.join("\n"))));
+            }
+            found.extend(list_input(&listed));
+            listed.clear();
+        }
+        let intro = line.trim_end();
+        if intro.ends_with("For example:")
+            || intro.ends_with(':') && intro.contains("following") && !intro.starts_with("Visually")
+        {
+            introduced = true;
+        }
+        offset += line.len() + 1;
+    }
+    if let Some((start, lines)) = block {
+        found.push((start, Found::Input(lines.join("\n"))));
+    }
+    found.extend(list_input(&listed));
+    for capture in INLINE.iter().flat_map(|phrase| phrase.captures_iter(text)) {
+        let input = capture.get(1).unwrap();
+        found.push((input.start(), Found::Input(input.as_str().to_string())));
+    }
+    found
+}
+
+/// The items of an indented list, as one input, when they are all different.
+/// The lines of a walk through an example ("Current frequency  0, change of
+/// +1; ...") all start the same way, so aren't an input.
+fn list_input(listed: &[(usize, &str)]) -> Option<(usize, Found)> {
+    let (start, _) = listed.first()?;
+    let items: Vec<&str> = listed.iter().map(|(_, item)| *item).collect();
+    let distinct = items
+        .iter()
+        .enumerate()
+        .all(|(i, item)| !items[i + 1..].contains(item));
+    if items.len() < 2 || !distinct {
+        return None;
+    }
+    Some((*start, Found::Input(items.join("\n"))))
+}
+
+/// The answers stated for an example: the result of a worked sum that ends
+/// its sentence, as in "the answer would be 10 * 24 = 240.", "a checksum of
+/// 4 \* 3 = 12." or "that sum is 1+1+2+10+11+12+2+99=138.", and the
+/// phrasings the directions use otherwise: "In this example, the correct
+/// order is CABDFE.", "producing the answer 4", "it would take 15 seconds",
+/// "the resulting polymer contains 10 units" and "only claim 3 is intact".
+fn answers(text: &str) -> Vec<(usize, Found)> {
+    lazy_static! {
+        static ref PHRASES: Vec<Regex> = [
+            r"\d\s*(?:\\?\*|\+|-|/)\s*\d+\s*=\s*(-?\w+)\.",
+            r"(?i)in this example, [^.]*? is (\w+)\.",
+            r"producing (?:the answer )?(\w+)\.",
+            r"it would take (\d+)",
+            r"contains (\d+) units",
+            r"only claim (\d+) is intact",
+        ]
+        .iter()
+        .map(|phrase| Regex::new(phrase).unwrap())
+        .collect();
+    }
+    let mut found: Vec<(usize, Found)> = PHRASES
+        .iter()
+        .flat_map(|phrase| phrase.captures_iter(text))
+        .map(|capture| capture.get(1).unwrap())
+        // Indented lines walk through the working ("the value of node A is
+        // 33+33+0=66.") rather than state the answer.
+        .filter(|answer| {
+            let line = text[..answer.start()].rfind('\n').map_or(0, |i| i + 1);
+            !text[line..].starts_with(char::is_whitespace)
+        })
+        .map(|answer| (answer.start(), Found::Answer(answer.as_str().to_string())))
+        .collect();
+    // An answer both worked out and phrased one of the ways above is still
+    // one answer.
+    found.sort_by_key(|(position, _)| *position);
+    found.dedup_by_key(|(position, _)| *position);
+    found
+}
+
+/// Examples given a line each, input and answer together, as in "+1, +1, -2
+/// results in 0" and "+1, -1 first reaches 0 twice".
+fn stated(text: &str) -> Vec<(usize, Found)> {
+    lazy_static! {
+        static ref STATED: Regex =
+            Regex::new(r"(?m)^\s*([+-]\d+(?:, [+-]\d+)*) (?:results in|first reaches)\s+(-?\d+)")
+                .unwrap();
+    }
+    STATED
+        .captures_iter(text)
+        .map(|capture| {
+            let input = capture.get(1).unwrap();
+            let answer = capture.get(2).unwrap().as_str().to_string();
+            (
+                input.start(),
+                Found::Example(input.as_str().to_string(), answer),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_block_example() {
+    let directions = r#"--- Day 7: The Sum of Its Parts ---
+
+For example, suppose you have the following instructions:
+
+Step C must be finished before step A can begin.
+Step A must be finished before step B can begin.
+
+Visually, these requirements look like this:
+
+  -->A--->B
+
+So, in this example, the correct order is CAB.
+--- Part Two ---
+
+In this example, it would take 15 seconds for two workers to complete these steps."#;
+    let input = "Step C must be finished before step A can begin.\n\
+                 Step A must be finished before step B can begin.";
+    assert_eq!(
+        vec![
+            Example {
+                part: Part::One,
+                input: input.to_string(),
+                answer: "CAB".to_string(),
+            },
+            Example {
+                part: Part::Two,
+                input: input.to_string(),
+                answer: "15".to_string(),
+            },
+        ],
+        examples(directions)
+    );
+}
+
+#[test]
+fn test_inline_example_and_drawings() {
+    let directions = r#"Each child node is itself a node. For example:
+
+2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2
+A----------------------------------
+    B----------- C-----------
+
+In this example, that sum is 1+1+2+10+11+12+2+99=138.
+
+Now, consider a larger example, dabAcCaCBAcCcaDA:
+
+dabAcCaCBAcCcaDA The first 'cC' is removed.
+
+After all possible reactions, the resulting polymer contains 10 units."#;
+    let found: Vec<(String, String)> = examples(directions)
+        .into_iter()
+        .map(|example| (example.input, example.answer))
+        .collect();
+    assert_eq!(
+        vec![
+            (
+                "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2".to_string(),
+                "138".to_string()
+            ),
+            ("dabAcCaCBAcCcaDA".to_string(), "10".to_string()),
+        ],
+        found
+    );
+}
+
+#[cfg(test)]
+fn example(part: Part, input: &str, answer: &str) -> Example {
+    Example {
+        part,
+        input: input.to_string(),
+        answer: answer.to_string(),
+    }
+}
+
+#[test]
+fn test_stated_examples() {
+    let directions = r#"For example, if the device displays frequency changes of +1, -2, +3, +1, then starting from a frequency of zero, the following changes would occur:
+
+    Current frequency  0, change of +1; resulting frequency  1.
+
+In this example, the resulting frequency is 3.
+
+Here are other example situations:
+
+    +1, +1, +1 results in  3
+    -1, -2, -3 results in -6
+
+--- Part Two ---
+
+In this example, the first frequency reached twice is 2.
+
+    +1, -1 first reaches 0 twice."#;
+    assert_eq!(
+        vec![
+            example(Part::One, "+1, -2, +3, +1", "3"),
+            example(Part::One, "+1, +1, +1", "3"),
+            example(Part::One, "-1, -2, -3", "-6"),
+            example(Part::Two, "+1, -2, +3, +1", "2"),
+            example(Part::Two, "+1, -1", "0"),
+        ],
+        examples(directions)
+    );
+}
+
+#[test]
+fn test_listed_example() {
+    let directions = r#"For example, if you see the following box IDs:
+
+    abcdef contains no letters that appear exactly two or three times.
+    bababc contains two a and three b, so it counts for both.
+    abbcde contains two b, but no letter appears exactly three times.
+    abcccd contains three c, but no letter appears exactly two times.
+    aabcdd contains two a and two d, but it only counts once.
+    abcdee contains two e.
+    ababab contains three a and three b, but it only counts once.
+
+Of these box IDs, four of them contain a letter which appears exactly twice, and three of them contain a letter which appears exactly three times. Multiplying these together produces a checksum of 4 \* 3 = 12."#;
+    assert_eq!(
+        vec![example(
+            Part::One,
+            "abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab",
+            "12"
+        )],
+        examples(directions)
+    );
+}
+
+#[test]
+fn test_worked_sums() {
+    // Only sums that end their sentence are answers, and not the ones in
+    // an indented walk through.
+    let directions = r#"Each child node is itself a node that has its own header, child nodes, and metadata. For example:
+
+2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2
+A----------------------------------
+    B----------- C-----------
+                     D-----
+
+The first check done on the license file is to simply add up all of the metadata entries. In this example, that sum is 1+1+2+10+11+12+2+99=138.
+--- Part Two ---
+
+If a node has no child nodes, its value is the sum of its metadata entries. So, the value of node B is 10+11+12=33, and the value of node D is 99.
+
+For example, again using the above nodes:
+
+    Node C has one metadata entry, 2. Because node C has only one child node, 2 references a child node which does not exist, and so the value of node C is 0.
+    Node A has three metadata entries: 1, 1, and 2. Because node B has a value of 33 and node C has a value of 0, the value of node A is 33+33+0=66.
+
+So, in this example, the value of the root node is 66."#;
+    let input = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2";
+    assert_eq!(
+        vec![
+            example(Part::One, input, "138"),
+            example(Part::Two, input, "66"),
+        ],
+        examples(directions)
+    );
+}
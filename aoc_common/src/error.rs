@@ -0,0 +1,119 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Everything that can go wrong between reading a puzzle input and printing
+/// its answer.
+pub enum Error {
+    /// The puzzle input could not be read.
+    Input { path: PathBuf, source: io::Error },
+    /// The puzzle input stopped being readable part way through a stream.
+    Read(io::Error),
+    /// Some of the puzzle input is not in the shape the puzzle describes.
+    Parse(ParseError),
+    /// Anything else, described in words.
+    Message(String),
+}
+
+pub type Result<T> = ::std::result::Result<T, Error>;
+
+/// A piece of the puzzle input that could not be parsed, with where it was
+/// found and what should have been there instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The 1-based line the text is on, once known.
+    pub line: Option<usize>,
+    /// The 1-based column the text starts at.
+    pub column: usize,
+    pub text: String,
+    pub expected: &'static str,
+}
+
+impl ParseError {
+    pub fn new(text: &str, column: usize, expected: &'static str) -> ParseError {
+        ParseError {
+            line: None,
+            column,
+            text: text.to_string(),
+            expected,
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> ParseError {
+        self.line = Some(line);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}, ", line)?;
+        }
+        write!(
+            f,
+            "column {}: expected {}, found {:?}",
+            self.column, self.expected, self.text
+        )
+    }
+}
+
+impl ::std::error::Error for ParseError {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Input { path, source } => {
+                write!(f, "Could not read input {}: {}", path.display(), source)
+            }
+            Error::Read(source) => write!(f, "Could not read the input: {}", source),
+            Error::Parse(error) => write!(f, "Could not parse the input at {}", error),
+            Error::Message(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+// `main` prints the `Debug` form of the error it returns, so make that the
+// readable message rather than the struct dump.
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl ::std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        match self {
+            Error::Input { source, .. } | Error::Read(source) => Some(source),
+            Error::Parse(error) => Some(error),
+            Error::Message(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Error {
+        Error::Parse(error)
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Error {
+        Error::Message(message)
+    }
+}
+
+impl<'a> From<&'a str> for Error {
+    fn from(message: &'a str) -> Error {
+        Error::Message(message.to_string())
+    }
+}
+
+#[test]
+fn test_parse_error_display() {
+    let error = ParseError::new("3x", 5, "a number").at_line(12);
+    assert_eq!(
+        "Could not parse the input at line 12, column 5: expected a number, found \"3x\"",
+        Error::from(error).to_string()
+    );
+}
@@ -0,0 +1,25 @@
+//! Seeded randomness for the synthetic input generators, so a seed always
+//! produces the same input.
+
+pub use rand::seq::{index, IndexedRandom, SliceRandom};
+pub use rand::Rng;
+use rand::SeedableRng;
+use rand_pcg::Pcg64;
+
+/// The random source every generator draws from.
+pub type Random = Pcg64;
+
+/// A random source that yields the same values every time for `seed`.
+pub fn seeded(seed: u64) -> Random {
+    Pcg64::seed_from_u64(seed)
+}
+
+#[test]
+fn test_seeded_is_repeatable() {
+    let draw = |seed| {
+        let mut random = seeded(seed);
+        (0..8).map(|_| random.random()).collect::<Vec<u32>>()
+    };
+    assert_eq!(draw(7), draw(7));
+    assert_ne!(draw(7), draw(8));
+}
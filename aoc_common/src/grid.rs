@@ -0,0 +1,319 @@
+//! A rectangle of cells addressed by `(x, y)` points, for the puzzles that
+//! lay things out on a plane. The rectangle can sit anywhere, so points
+//! don't have to be shifted to start at zero first.
+
+use crate::{Error, Result};
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A position on the plane, `x` growing to the right and `y` downwards.
+pub type Point = (i64, i64);
+
+/// The most cells a grid can have, about 16 million. Input asking for a
+/// bigger plane is an error rather than more memory than there is.
+pub const MAX_AREA: usize = 1 << 24;
+
+/// Every point from `min` to `max` inclusive, holding a `T` each.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    min: Point,
+    width: usize,
+    height: usize,
+    /// Row by row, from `min` onwards.
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid from `min` to `max` inclusive with every cell set to `value`.
+    /// It is empty when `max` is left of or above `min`, and an error when
+    /// it would have more than [`MAX_AREA`] cells.
+    pub fn new(min: Point, max: Point, value: T) -> Result<Grid<T>> {
+        let (width, height) = size(min, max)?;
+        Ok(Grid {
+            min,
+            width,
+            height,
+            cells: vec![value; width * height],
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid from `min` to `max` inclusive holding `cell(point)` at each
+    /// point, limited to [`MAX_AREA`] cells like [`Grid::new`].
+    pub fn from_fn<F>(min: Point, max: Point, mut cell: F) -> Result<Grid<T>>
+    where
+        F: FnMut(Point) -> T,
+    {
+        let (width, height) = size(min, max)?;
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(cell((min.0 + x as i64, min.1 + y as i64)));
+            }
+        }
+        Ok(Grid {
+            min,
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// The top left point.
+    pub fn min(&self) -> Point {
+        self.min
+    }
+
+    /// The bottom right point, which is left of or above `min` in an empty
+    /// grid.
+    pub fn max(&self) -> Point {
+        (
+            self.min.0 + self.width as i64 - 1,
+            self.min.1 + self.height as i64 - 1,
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.offset(point).is_some()
+    }
+
+    /// Whether `point` is in the outermost ring of cells.
+    pub fn on_edge(&self, point: Point) -> bool {
+        let max = self.max();
+        self.contains(point)
+            && (point.0 == self.min.0
+                || point.1 == self.min.1
+                || point.0 == max.0
+                || point.1 == max.1)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(move |i| &mut self.cells[i])
+    }
+
+    /// Every point with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let (min, width) = (self.min, self.width);
+        self.cells.iter().enumerate().map(move |(i, cell)| {
+            let point = (min.0 + (i % width) as i64, min.1 + (i / width) as i64);
+            (point, cell)
+        })
+    }
+
+    /// Every cell, row by row.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The points above, left of, right of and below `point` that are in
+    /// the grid, with their cells.
+    pub fn neighbors(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        let (x, y) = point;
+        let around = [(x, y - 1), (x - 1, y), (x + 1, y), (x, y + 1)];
+        IntoIterator::into_iter(around)
+            .filter_map(move |neighbor| self.get(neighbor).map(|cell| (neighbor, cell)))
+    }
+
+    /// The cells in row `y`, left to right, if it is in the grid.
+    pub fn row(&self, y: i64) -> Option<&[T]> {
+        let row = usize::try_from(y.checked_sub(self.min.1)?).ok()?;
+        if row >= self.height {
+            return None;
+        }
+        Some(&self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` refuses a size of 0, which a grid without columns has.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells in column `x`, top to bottom, which is nothing when `x` is
+    /// outside the grid.
+    pub fn column(&self, x: i64) -> impl Iterator<Item = &T> {
+        let column = x
+            .checked_sub(self.min.0)
+            .and_then(|column| usize::try_from(column).ok())
+            .filter(|&column| column < self.width);
+        let cells = match column {
+            Some(column) => &self.cells[column..],
+            None => &[],
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// Every column, left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width as i64).map(move |x| self.column(self.min.0 + x))
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.0.checked_sub(self.min.0)?).ok()?;
+        let y = usize::try_from(point.1.checked_sub(self.min.1)?).ok()?;
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    fn outside(&self, point: Point) -> ! {
+        panic!(
+            "{:?} is outside the grid from {:?} to {:?}",
+            point,
+            self.min,
+            self.max()
+        )
+    }
+}
+
+fn size(min: Point, max: Point) -> Result<(usize, usize)> {
+    let too_big = || -> Error {
+        format!(
+            "A grid from {:?} to {:?} is too big, it can have at most {} cells",
+            min, max, MAX_AREA
+        )
+        .into()
+    };
+    let length = |from: i64, to: i64| {
+        if to < from {
+            Some(0)
+        } else {
+            usize::try_from(i128::from(to) - i128::from(from) + 1).ok()
+        }
+    };
+    let width = length(min.0, max.0).ok_or_else(too_big)?;
+    let height = length(min.1, max.1).ok_or_else(too_big)?;
+    if width == 0 || height == 0 {
+        return Ok((0, 0));
+    }
+    match width.checked_mul(height) {
+        Some(area) if area <= MAX_AREA => Ok((width, height)),
+        _ => Err(too_big()),
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.offset(point) {
+            Some(i) => &self.cells[i],
+            None => self.outside(point),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.offset(point) {
+            Some(i) => &mut self.cells[i],
+            None => self.outside(point),
+        }
+    }
+}
+
+// The cells laid out as they are on the plane, which is easier to read in a
+// failing test than the flat list.
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Grid from {:?} to {:?}", self.min, self.max())?;
+        for row in self.rows() {
+            writeln!(f, "{:?}", row)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_negative_origin() {
+    let mut grid = Grid::from_fn((-2, -1), (1, 0), |(x, y)| x * 10 + y).unwrap();
+    assert_eq!((4, 2), (grid.width(), grid.height()));
+    assert_eq!(((-2, -1), (1, 0)), (grid.min(), grid.max()));
+    assert_eq!(-21, grid[(-2, -1)]);
+    assert_eq!(Some(&10), grid.get((1, 0)));
+    assert_eq!(None, grid.get((2, 0)));
+    assert_eq!(None, grid.get((-3, 0)));
+    assert_eq!(None, grid.get((0, i64::MIN)));
+    grid[(0, 0)] = 7;
+    *grid.get_mut((0, -1)).unwrap() += 1;
+    assert_eq!(
+        vec![&[-21, -11, 0, 9][..], &[-20, -10, 7, 10][..]],
+        grid.rows().collect::<Vec<_>>()
+    );
+    assert_eq!(Some(&[-20, -10, 7, 10][..]), grid.row(0));
+    assert_eq!(None, grid.row(1));
+    assert_eq!(vec![&0, &7], grid.column(0).collect::<Vec<_>>());
+    assert_eq!(0, grid.column(5).count());
+    assert_eq!(
+        vec![vec![-21, -20], vec![-11, -10], vec![0, 7], vec![9, 10]],
+        grid.columns()
+            .map(|column| column.cloned().collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![((-2, -1), &-21), ((-1, -1), &-11), ((0, -1), &0)],
+        grid.iter().take(3).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_neighbors_and_edges() {
+    let grid = Grid::new((0, 0), (2, 2), '.').unwrap();
+    let neighbors = |point| {
+        grid.neighbors(point)
+            .map(|(point, _)| point)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(vec![(1, 0), (0, 1), (2, 1), (1, 2)], neighbors((1, 1)));
+    assert_eq!(vec![(1, 0), (0, 1)], neighbors((0, 0)));
+    assert_eq!(vec![(2, 2)], neighbors((3, 2)));
+    assert!(grid.on_edge((0, 1)) && grid.on_edge((2, 2)));
+    assert!(!grid.on_edge((1, 1)) && !grid.on_edge((3, 1)));
+}
+
+#[test]
+fn test_empty() {
+    let grid = Grid::new((0, 0), (-1, 5), 0u8).unwrap();
+    assert_eq!((0, 0), (grid.width(), grid.height()));
+    assert!(!grid.contains((0, 0)));
+    assert_eq!(0, grid.rows().count());
+    assert_eq!(0, grid.columns().count());
+    assert_eq!(0, grid.iter().count());
+}
+
+#[test]
+fn test_too_big() {
+    assert!(Grid::new((0, 0), (4095, 4095), 0u8).is_ok());
+    assert_eq!(
+        "A grid from (0, 0) to (4096, 4095) is too big, it can have at most 16777216 cells",
+        Grid::new((0, 0), (4096, 4095), 0u8)
+            .unwrap_err()
+            .to_string()
+    );
+    assert!(Grid::from_fn((0, 0), (100_000, 100_000), |_| 0u8).is_err());
+    assert!(Grid::new((i64::MIN, 0), (i64::MAX, 0), 0u8).is_err());
+    assert!(Grid::new((i64::MAX, 0), (i64::MIN, 0), 0u8).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "(3, 0) is outside the grid from (0, 0) to (2, 2)")]
+fn test_index_out_of_bounds() {
+    let grid = Grid::new((0, 0), (2, 2), 0u8).unwrap();
+    let _ = grid[(3, 0)];
+}
@@ -0,0 +1,127 @@
+//! Pictures of a puzzle's workings, one color per grid cell, written out as
+//! PPM or PNG files to look at when an answer comes out wrong.
+
+use crate::{Grid, Result};
+
+/// A color as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// A picture with one pixel per point of the grid.
+pub type Image = Grid<Rgb>;
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// The color of `value` on a scale up to `max`, from black through red
+/// and yellow to white.
+pub fn heat(value: u32, max: u32) -> Rgb {
+    if max == 0 {
+        return BLACK;
+    }
+    let t = f64::from(value.min(max)) / f64::from(max);
+    let channel = |start: f64| ((t * 3.0 - start).clamp(0.0, 1.0) * 255.0).round() as u8;
+    [channel(0.0), channel(1.0), channel(2.0)]
+}
+
+/// A color for the `index`th of many things, each different enough from
+/// its neighbours to tell them apart.
+pub fn color(index: usize) -> Rgb {
+    // Stepping round the color wheel by the golden ratio never lands on
+    // the same hue twice and keeps consecutive hues far apart.
+    let hue = (index as f64 * 0.618_033_988_75).fract() * 6.0;
+    let (saturation, value) = (0.65, 0.9);
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let channel = |c: f64| ((c + value - chroma) * 255.0).round() as u8;
+    [channel(r), channel(g), channel(b)]
+}
+
+/// `color` halfway to white.
+pub fn lighten(color: Rgb) -> Rgb {
+    let channel = |c: u8| c + (255 - c) / 2;
+    [channel(color[0]), channel(color[1]), channel(color[2])]
+}
+
+/// The rows of pixels with each cell drawn as a `scale` pixel square.
+fn pixels(image: &Image, scale: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(image.width() * image.height() * scale * scale * 3);
+    for row in image.rows() {
+        for _ in 0..scale {
+            for cell in row {
+                for _ in 0..scale {
+                    pixels.extend_from_slice(cell);
+                }
+            }
+        }
+    }
+    pixels
+}
+
+/// `image` as a binary PPM file.
+pub fn ppm(image: &Image, scale: usize) -> Vec<u8> {
+    let mut ppm = format!(
+        "P6\n{} {}\n255\n",
+        image.width() * scale,
+        image.height() * scale
+    )
+    .into_bytes();
+    ppm.extend(pixels(image, scale));
+    ppm
+}
+
+/// `image` as a PNG file, which has to have at least one pixel.
+pub fn png(image: &Image, scale: usize) -> Result<Vec<u8>> {
+    let mut png = vec![];
+    let mut encoder = ::png::Encoder::new(
+        &mut png,
+        (image.width() * scale) as u32,
+        (image.height() * scale) as u32,
+    );
+    encoder.set_color(::png::ColorType::Rgb);
+    encoder.set_depth(::png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&pixels(image, scale)))
+        .map_err(|error| format!("Could not encode the picture: {}", error))?;
+    Ok(png)
+}
+
+#[test]
+fn test_colors() {
+    assert_eq!(BLACK, heat(0, 6));
+    assert_eq!([255, 0, 0], heat(2, 6));
+    assert_eq!([255, 255, 0], heat(4, 6));
+    assert_eq!(WHITE, heat(9, 6));
+    assert_eq!(BLACK, heat(3, 0));
+    assert_eq!([127, 191, 255], lighten([0, 127, 255]));
+    let colors: Vec<Rgb> = (0..50).map(color).collect();
+    for (i, a) in colors.iter().enumerate() {
+        assert!(colors[i + 1..].iter().all(|b| a != b), "{:?} repeats", a);
+    }
+}
+
+#[test]
+fn test_encode() {
+    let image = Grid::from_fn((-1, 0), (0, 0), |(x, _)| if x < 0 { BLACK } else { WHITE }).unwrap();
+    let mut expected = b"P6\n4 2\n255\n".to_vec();
+    for _ in 0..2 {
+        expected.extend(&[0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]);
+    }
+    assert_eq!(expected, ppm(&image, 2));
+
+    let encoded = png(&image, 2).unwrap();
+    assert_eq!(b"\x89PNG\r\n\x1a\n", &encoded[..8]);
+    let mut decoder = ::png::Decoder::new(&encoded[..]).read_info().unwrap();
+    let mut decoded = vec![0; decoder.output_buffer_size()];
+    decoder.next_frame(&mut decoded).unwrap();
+    assert_eq!(&expected[11..], &decoded[..]);
+    assert!(png(&Grid::new((0, 0), (-1, -1), BLACK).unwrap(), 1).is_err());
+}
@@ -0,0 +1,51 @@
+use crate::{Error, Result};
+use std::env;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{self, BufReader};
+use std::path::Path;
+
+/// Read the whole puzzle input at `path` into a string, or all of stdin when
+/// `path` is `-`.
+pub fn read_input<P: AsRef<Path>>(path: P) -> Result<String> {
+    let path = path.as_ref();
+    let mut contents = String::new();
+    let read = if path == Path::new("-") {
+        io::stdin().read_to_string(&mut contents)
+    } else {
+        File::open(path).and_then(|file| BufReader::new(file).read_to_string(&mut contents))
+    };
+    read.map_err(|source| Error::Input {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(contents)
+}
+
+/// Open the puzzle input at `path` for reading a piece at a time, or stdin
+/// when `path` is `-`.
+pub fn open_input<P: AsRef<Path>>(path: P) -> Result<Box<dyn BufRead>> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    let file = File::open(path).map_err(|source| Error::Input {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// The input path passed as the first command line argument, or `default`
+/// when there is none.
+pub fn input_path_arg(default: &str) -> String {
+    env::args().nth(1).unwrap_or_else(|| default.to_string())
+}
+
+#[test]
+fn test_read_input_names_the_path() {
+    let error = read_input("does/not/exist").unwrap_err();
+    assert!(error.to_string().contains("does/not/exist"));
+    let error = open_input("does/not/exist").err().unwrap();
+    assert!(error.to_string().contains("does/not/exist"));
+}
@@ -0,0 +1,25 @@
+//! Shared plumbing for the Advent of Code 2018 solutions: the error type,
+//! reading puzzle inputs, the small parsing helpers every day needs and a
+//! grid for the puzzles set on a plane, which can be drawn as a picture.
+
+pub mod day;
+pub mod directions;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod parse;
+pub mod solution;
+
+pub use crate::day::{Day, Part};
+pub use crate::directions::{examples, Example};
+pub use crate::error::{Error, ParseError, Result};
+pub use crate::grid::{Grid, Point};
+pub use crate::image::Image;
+pub use crate::input::{input_path_arg, open_input, read_input};
+pub use crate::parse::{
+    parse_lines, parse_lines_from, parse_lines_with, parse_list, parse_list_from, parse_number,
+    parse_whitespace, parse_whitespace_from,
+};
+pub use crate::solution::{AnySolution, Solution};
@@ -0,0 +1,209 @@
+use crate::{Error, ParseError, Result};
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+/// Parse every line of `input` as a `T`, tagging a failure with its line.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr<Err = ParseError>,
+{
+    parse_lines_with(input, str::parse)
+}
+
+/// Parse every line of `input` with `parse`, tagging a failure with its
+/// line.
+pub fn parse_lines_with<T, F>(input: &str, mut parse: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> ::std::result::Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|error| error.at_line(i + 1).into()))
+        .collect()
+}
+
+/// Like [`parse_lines_with`], but reading one line at a time from `reader`
+/// so the whole input never has to be in memory.
+pub fn parse_lines_from<R, T, F>(reader: R, mut parse: F) -> impl Iterator<Item = Result<T>>
+where
+    R: BufRead,
+    F: FnMut(&str) -> ::std::result::Result<T, ParseError>,
+{
+    reader.lines().enumerate().map(move |(i, line)| {
+        let line = line.map_err(Error::Read)?;
+        parse(&line).map_err(|error| error.at_line(i + 1).into())
+    })
+}
+
+/// Parse every whitespace separated token of `input` as a `T`.
+pub fn parse_whitespace<T: FromStr>(input: &str, expected: &'static str) -> Result<Vec<T>> {
+    parse_whitespace_from(input.as_bytes(), expected).collect()
+}
+
+/// Like [`parse_whitespace`], but reading from `reader` a token at a time,
+/// which also copes with one enormous line.
+pub fn parse_whitespace_from<R: BufRead, T: FromStr>(
+    reader: R,
+    expected: &'static str,
+) -> impl Iterator<Item = Result<T>> {
+    parse_tokens_from(reader, |byte| byte.is_ascii_whitespace(), expected)
+}
+
+/// Parse every token of `input` as a `T`, however the list is written: one
+/// per line, on one line separated by commas as in "+1, -2, +3", or any mix
+/// of commas, whitespace and blank lines.
+pub fn parse_list<T: FromStr>(input: &str, expected: &'static str) -> Result<Vec<T>> {
+    parse_list_from(input.as_bytes(), expected).collect()
+}
+
+/// Like [`parse_list`], but reading from `reader` a token at a time.
+pub fn parse_list_from<R: BufRead, T: FromStr>(
+    reader: R,
+    expected: &'static str,
+) -> impl Iterator<Item = Result<T>> {
+    parse_tokens_from(
+        reader,
+        |byte| byte == b',' || byte.is_ascii_whitespace(),
+        expected,
+    )
+}
+
+fn parse_tokens_from<R: BufRead, T: FromStr>(
+    reader: R,
+    separator: fn(u8) -> bool,
+    expected: &'static str,
+) -> impl Iterator<Item = Result<T>> {
+    Tokens {
+        bytes: reader.bytes(),
+        separator,
+        line: 1,
+        column: 1,
+    }
+    .map(move |token| {
+        let (line, column, token) = token.map_err(Error::Read)?;
+        parse_number(&token, column, expected).map_err(|error| error.at_line(line).into())
+    })
+}
+
+/// Parse `text`, found at `column`, as a number (or anything else with a
+/// `FromStr`).
+pub fn parse_number<T: FromStr>(
+    text: &str,
+    column: usize,
+    expected: &'static str,
+) -> ::std::result::Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(text, column, expected))
+}
+
+/// The tokens read from a stream of bytes, split wherever `separator` says,
+/// with the 1-based line and column each starts at.
+struct Tokens<R> {
+    bytes: io::Bytes<R>,
+    separator: fn(u8) -> bool,
+    line: usize,
+    column: usize,
+}
+
+impl<R: BufRead> Iterator for Tokens<R> {
+    type Item = io::Result<(usize, usize, String)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut token = vec![];
+        let mut start = (self.line, self.column);
+        for byte in &mut self.bytes {
+            let byte = match byte {
+                Ok(byte) => byte,
+                Err(error) => return Some(Err(error)),
+            };
+            let at = (self.line, self.column);
+            if byte == b'\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+            if !(self.separator)(byte) {
+                if token.is_empty() {
+                    start = at;
+                }
+                token.push(byte);
+            } else if !token.is_empty() {
+                break;
+            }
+        }
+        if token.is_empty() {
+            return None;
+        }
+        let token = String::from_utf8_lossy(&token).into_owned();
+        Some(Ok((start.0, start.1, token)))
+    }
+}
+
+#[test]
+fn test_parse_lines() {
+    let parse = |line: &str| parse_number::<i32>(line, 1, "a number");
+    assert_eq!(
+        vec![1, -2, 3],
+        parse_lines_with("+1\n-2\n+3", parse).unwrap()
+    );
+    assert_eq!(
+        "Could not parse the input at line 2, column 1: expected a number, found \"foo\"",
+        parse_lines_with("+1\nfoo", parse).unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_parse_whitespace() {
+    assert_eq!(
+        vec![2, 3, 0, 3],
+        parse_whitespace::<u32>("2 3\n0  3\n", "a number").unwrap()
+    );
+    assert_eq!(
+        "Could not parse the input at line 2, column 4: expected a number, found \"-3\"",
+        parse_whitespace::<u32>("2 3\n0  -3", "a number")
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[test]
+fn test_parse_from_reader() {
+    let parse = |line: &str| parse_number::<i32>(line, 1, "a number");
+    let changes: Result<Vec<i32>> = parse_lines_from("+1\n-2\r\n+3\n".as_bytes(), parse).collect();
+    assert_eq!(vec![1, -2, 3], changes.unwrap());
+    let mut changes = parse_lines_from("+1\nfoo\n+3".as_bytes(), parse);
+    assert_eq!(1, changes.next().unwrap().unwrap());
+    assert_eq!(
+        "Could not parse the input at line 2, column 1: expected a number, found \"foo\"",
+        changes.next().unwrap().unwrap_err().to_string()
+    );
+
+    let numbers: Result<Vec<u32>> =
+        parse_whitespace_from(" 2 3\n\n0\t3".as_bytes(), "a number").collect();
+    assert_eq!(vec![2, 3, 0, 3], numbers.unwrap());
+}
+
+#[test]
+fn test_parse_list() {
+    let expected = vec![1, -2, 3, 1];
+    assert_eq!(
+        expected,
+        parse_list::<i32>("+1, -2, +3, +1", "a number").unwrap()
+    );
+    assert_eq!(
+        expected,
+        parse_list::<i32>("+1\n-2\n\n3 +1\n", "a number").unwrap()
+    );
+    assert_eq!(
+        expected,
+        parse_list::<i32>("1,-2,\r\n3,\t+1,", "a number").unwrap()
+    );
+    assert_eq!(
+        "Could not parse the input at line 2, column 5: expected a number, found \"+3x\"",
+        parse_list::<i32>("+1,\n-2, +3x, +1", "a number")
+            .unwrap_err()
+            .to_string()
+    );
+}
@@ -0,0 +1,114 @@
+use crate::{Error, Image, Part, Result};
+use std::any::Any;
+use std::fmt::Display;
+use std::io::BufRead;
+
+/// A day's puzzle: parse the input once, then answer both parts from the
+/// parsed form.
+pub trait Solution {
+    type Parsed;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed>;
+
+    /// Parse the input as it is read from `reader`. By default this reads it
+    /// all into a string first; days with big inputs parse as they read.
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<Self::Parsed> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input).map_err(Error::Read)?;
+        self.parse(&input)
+    }
+
+    fn part_1(&self, parsed: &Self::Parsed) -> Result<Self::Answer1>;
+    fn part_2(&self, parsed: &Self::Parsed) -> Result<Self::Answer2>;
+
+    /// A picture of how the answers are worked out, for the puzzles where
+    /// one helps.
+    fn render(&self, _parsed: &Self::Parsed) -> Result<Image> {
+        Err("There is no picture of this puzzle".into())
+    }
+
+    fn solve_part_1(&self, input: &str) -> Result<Self::Answer1> {
+        self.part_1(&self.parse(input)?)
+    }
+
+    fn solve_part_2(&self, input: &str) -> Result<Self::Answer2> {
+        self.part_2(&self.parse(input)?)
+    }
+}
+
+/// The object safe face of a [`Solution`], so days with different parsed
+/// and answer types can sit in one table. Implemented for every `Solution`.
+pub trait AnySolution: Sync {
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn parse_reader_any(&self, reader: &mut dyn BufRead) -> Result<Box<dyn Any>>;
+    fn part_any(&self, part: Part, parsed: &dyn Any) -> Result<String>;
+    fn render_any(&self, parsed: &dyn Any) -> Result<Image>;
+}
+
+impl<S> AnySolution for S
+where
+    S: Solution + Sync,
+    S::Parsed: 'static,
+{
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn parse_reader_any(&self, reader: &mut dyn BufRead) -> Result<Box<dyn Any>> {
+        Ok(Box::new(self.parse_reader(reader)?))
+    }
+
+    fn part_any(&self, part: Part, parsed: &dyn Any) -> Result<String> {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .ok_or("Parsed input belongs to a different solution")?;
+        match part {
+            Part::One => self.part_1(parsed).map(|answer| answer.to_string()),
+            Part::Two => self.part_2(parsed).map(|answer| answer.to_string()),
+        }
+    }
+
+    fn render_any(&self, parsed: &dyn Any) -> Result<Image> {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .ok_or("Parsed input belongs to a different solution")?;
+        self.render(parsed)
+    }
+}
+
+#[cfg(test)]
+struct Sum;
+
+#[cfg(test)]
+impl Solution for Sum {
+    type Parsed = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>> {
+        crate::parse_whitespace(input, "a number")
+    }
+
+    fn part_1(&self, parsed: &Vec<i32>) -> Result<i32> {
+        Ok(parsed.iter().sum())
+    }
+
+    fn part_2(&self, parsed: &Vec<i32>) -> Result<usize> {
+        Ok(parsed.len())
+    }
+}
+
+#[test]
+fn test_any_solution() {
+    let solution: &dyn AnySolution = &Sum;
+    let parsed = solution.parse_any("1\n2\n3").unwrap();
+    assert_eq!("6", solution.part_any(Part::One, &*parsed).unwrap());
+    let parsed = solution
+        .parse_reader_any(&mut "1\n2\n3".as_bytes())
+        .unwrap();
+    assert_eq!("6", solution.part_any(Part::One, &*parsed).unwrap());
+    assert_eq!("3", solution.part_any(Part::Two, &*parsed).unwrap());
+    assert!(solution.part_any(Part::One, &"1").is_err());
+}
@@ -0,0 +1,126 @@
+use aoc_common::generate::{seeded, Rng};
+use aoc_common::{Day, Result, Solution};
+
+pub const DAY: Day = Day {
+    number: 5,
+    name: "day_5",
+    input: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/part_1"),
+    answers: concat!(env!("CARGO_MANIFEST_DIR"), "/answers"),
+    solution: &Day5,
+    directions: concat!(env!("CARGO_MANIFEST_DIR"), "/directions.md"),
+    example: &Day5,
+    generate,
+};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str) -> Result<String> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part_1(&self, polymer: &String) -> Result<u32> {
+        part_1(polymer)
+    }
+
+    fn part_2(&self, polymer: &String) -> Result<u32> {
+        part_2(polymer)
+    }
+}
+
+struct Part1 {
+    value: Vec<char>,
+}
+
+fn opposite(value: char) -> char {
+    if value.is_uppercase() {
+        value.to_lowercase().next().unwrap()
+    } else {
+        value.to_uppercase().next().unwrap()
+    }
+}
+
+impl Part1 {
+    fn add_char(self, new_value: char) -> Part1 {
+        match self.value.as_slice().split_first() {
+            None => Part1 {
+                value: vec![new_value],
+            },
+            Some((&head, tail)) => {
+                if opposite(new_value) == head {
+                    Part1 { value: tail.into() }
+                } else {
+                    let mut value = vec![new_value, head];
+                    let mut tail_vec: Vec<char> = tail.into();
+                    value.append(&mut tail_vec);
+                    Part1 { value }
+                }
+            }
+        }
+    }
+}
+
+pub fn part_1(input: &str) -> Result<u32> {
+    let mut answer = Part1 { value: vec![] };
+    for c in input.chars() {
+        answer = answer.add_char(c);
+    }
+    Ok(answer.value.len() as u32)
+}
+
+#[test]
+fn test_part_1() {
+    assert_eq!(0, part_1("aA").unwrap());
+    assert_eq!(0, part_1("abBA").unwrap());
+    assert_eq!(4, part_1("abAB").unwrap());
+    assert_eq!(6, part_1("aabAAB").unwrap());
+}
+
+pub fn part_2(input: &str) -> Result<u32> {
+    let mut answer = Part1 { value: vec![] };
+    for c in input.chars() {
+        answer = answer.add_char(c);
+    }
+    for remove_char in "abcdefghijklmnopqrstuvwxyz".chars() {
+        let opposite_remove_char = opposite(remove_char);
+        let mut local_answer = Part1 { value: vec![] };
+        for c in input.chars() {
+            if !(c == remove_char || c == opposite_remove_char) {
+                local_answer = local_answer.add_char(c);
+            }
+        }
+        if answer.value.len() > local_answer.value.len() {
+            answer = local_answer;
+        }
+    }
+    Ok(answer.value.len() as u32)
+}
+
+/// A polymer of `size` units. About a third of the units are the opposite
+/// polarity of the unit before them, so plenty of them react.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut random = seeded(seed);
+    let mut polymer = String::with_capacity(size + 1);
+    let mut last: Option<char> = None;
+    for _ in 0..size {
+        let unit = match last {
+            Some(last) if random.random_bool(0.3) => opposite(last),
+            _ => {
+                let letter = random.random_range(b'a'..=b'z') as char;
+                if random.random_bool(0.5) {
+                    letter.to_ascii_uppercase()
+                } else {
+                    letter
+                }
+            }
+        };
+        polymer.push(unit);
+        last = Some(unit);
+    }
+    polymer.push('\n');
+    polymer
+}
@@ -0,0 +1,381 @@
+use aoc_common::generate::{seeded, Rng};
+use aoc_common::image::{color, lighten, BLACK, WHITE};
+use aoc_common::{
+    parse_lines, parse_lines_from, parse_number, Day, Grid, Image, ParseError, Point, Result,
+    Solution,
+};
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::io::BufRead;
+use std::{cmp, str::FromStr};
+
+type Id = usize;
+
+pub const DAY: Day = Day {
+    number: 6,
+    name: "day_6",
+    input: concat!(env!("CARGO_MANIFEST_DIR"), "/input"),
+    answers: concat!(env!("CARGO_MANIFEST_DIR"), "/answers"),
+    solution: &Day6 {
+        max_distance: 10000,
+    },
+    directions: concat!(env!("CARGO_MANIFEST_DIR"), "/directions"),
+    example: &Day6 { max_distance: 32 },
+    generate,
+};
+
+pub struct Day6 {
+    /// Part 2 counts the locations whose total distance is below this.
+    pub max_distance: u32,
+}
+
+impl Solution for Day6 {
+    type Parsed = Vec<Coord>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Coord>> {
+        parse_lines(input)
+    }
+
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<Vec<Coord>> {
+        parse_lines_from(reader, str::parse).collect()
+    }
+
+    fn part_1(&self, coords: &Vec<Coord>) -> Result<u32> {
+        coords_most_area(coords)
+    }
+
+    fn part_2(&self, coords: &Vec<Coord>) -> Result<u32> {
+        coords_sum(coords, self.max_distance)
+    }
+
+    /// Each location in the color of its closest coordinate, black where
+    /// that is a tie and lighter within `max_distance` in total of them all.
+    /// The coordinates themselves are white.
+    fn render(&self, coords: &Vec<Coord>) -> Result<Image> {
+        let distances = total_distances(coords, self.max_distance)?;
+        Grid::from_fn(distances.min(), distances.max(), |point| {
+            if coords.iter().any(|coord| (coord.x, coord.y) == point) {
+                return WHITE;
+            }
+            let color = closest(coords, point).map_or(BLACK, color);
+            if distances[point] < u64::from(self.max_distance) {
+                lighten(color)
+            } else {
+                color
+            }
+        })
+    }
+}
+
+/// Worked out in `i64`, which holds the difference of any two coordinates
+/// with room to spare.
+fn manhattan_distance(a: &Coord, b: &Coord) -> u64 {
+    (a.x - b.x).unsigned_abs() + (a.y - b.y).unsigned_abs()
+}
+
+#[test]
+fn test_manhattan() {
+    let p1 = ("1, 1").parse().unwrap();
+    let p2 = ("1, 2").parse().unwrap();
+    let p3 = ("2, 2").parse().unwrap();
+    let p4 = ("4, 4").parse().unwrap();
+    assert_eq!(0, manhattan_distance(&p1, &p1));
+    assert_eq!(1, manhattan_distance(&p1, &p2));
+    assert_eq!(2, manhattan_distance(&p1, &p3));
+    assert_eq!(6, manhattan_distance(&p1, &p4));
+    let p5 = ("2147483647, 0").parse().unwrap();
+    let p6 = ("2147483649, 0").parse().unwrap();
+    assert_eq!(2, manhattan_distance(&p5, &p6));
+    let p7 = ("4294967295, 4294967295").parse().unwrap();
+    assert_eq!(8589934588, manhattan_distance(&p1, &p7));
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Coord {
+    x: i64,
+    y: i64,
+}
+
+impl Coord {
+    fn empty() -> Coord {
+        Coord { x: 0, y: 0 }
+    }
+
+    fn empty_max() -> Coord {
+        Coord {
+            x: i64::MAX,
+            y: i64::MAX,
+        }
+    }
+
+    fn expand_bound(mut self, other: &Coord) -> Coord {
+        self.x = cmp::max(self.x, other.x);
+        self.y = cmp::max(self.y, other.y);
+        self
+    }
+    fn decrease_bound(mut self, other: &Coord) -> Coord {
+        self.x = cmp::min(self.x, other.x);
+        self.y = cmp::min(self.y, other.y);
+        self
+    }
+}
+
+impl FromStr for Coord {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> ::std::result::Result<Coord, ParseError> {
+        const EXPECTED: &str = "a coordinate like 1, 6";
+        let mut values = vec![];
+        let mut column = 1;
+        for x in value.split(", ") {
+            values.push(parse_number::<u32>(x, column, EXPECTED)?);
+            column += x.len() + 2;
+        }
+        if values.len() != 2 {
+            return Err(ParseError::new(value, 1, EXPECTED));
+        }
+        Ok(Coord {
+            x: i64::from(values[0]),
+            y: i64::from(values[1]),
+        })
+    }
+}
+
+/// The coordinate closest to `point`, or none when two or more are equally
+/// close.
+fn closest(coords: &[Coord], (x, y): Point) -> Option<Id> {
+    let current = Coord { x, y };
+    let with_distance: Vec<_> = coords
+        .iter()
+        .enumerate()
+        .map(|(id, coord)| (id, manhattan_distance(&current, coord)))
+        .collect();
+    if let Some(&(lowest_id, lowest_dist)) = with_distance.iter().min_by_key(|(_, dist)| *dist) {
+        match with_distance
+            .iter()
+            .filter(|(_, dist)| *dist == lowest_dist)
+            .count()
+        {
+            1 => Some(lowest_id),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// The coordinate closest to each location from the top left to the bottom
+/// right coordinate, or none where two or more are equally close.
+pub fn closest_coords(coords: &[Coord]) -> Result<Grid<Option<Id>>> {
+    let bounds = coords.iter().fold(Coord::empty(), |x, y| x.expand_bound(y));
+
+    let lower_bounds = coords
+        .iter()
+        .fold(Coord::empty_max(), |x, y| x.decrease_bound(y));
+
+    Grid::from_fn(
+        (lower_bounds.x, lower_bounds.y),
+        (bounds.x, bounds.y),
+        |point| closest(coords, point),
+    )
+}
+
+pub fn coords_most_area(coords: &[Coord]) -> Result<u32> {
+    let closest = closest_coords(coords)?;
+    // Collect totals
+    type Count = u32;
+    let mut totals: HashMap<Id, Count> = HashMap::new();
+    for id in closest.values().flatten() {
+        totals.entry(*id).and_modify(|x| *x += 1).or_insert(1);
+    }
+
+    // Remove the infinite (the edges)
+    for (point, cell) in closest.iter() {
+        if let Some(id) = cell {
+            if closest.on_edge(point) {
+                totals.remove(id);
+            }
+        }
+    }
+
+    // When every area is infinite there is no largest finite one, which
+    // counts as 0.
+    let total = totals
+        .iter()
+        .max_by_key(|(_, area)| *area)
+        .map_or(0, |(_, total)| *total);
+
+    Ok(total)
+}
+
+/// The total distance to every coordinate from each location that could be
+/// under `max_distance`, and a few that can't. Only for drawing, since the
+/// grid grows with `max_distance` and soon goes past what one can hold.
+pub fn total_distances(coords: &[Coord], max_distance: u32) -> Result<Grid<u64>> {
+    let bounds = coords.iter().fold(Coord::empty(), |x, y| x.expand_bound(y));
+    let lower_bounds = coords
+        .iter()
+        .fold(Coord::empty_max(), |x, y| x.decrease_bound(y));
+    // A location `d` steps outside the bounding box is at least `d` away
+    // from every coordinate, so nothing further out can be under the total.
+    let offset = i64::from(max_distance) / coords.len().max(1) as i64 + 1;
+
+    Grid::from_fn(
+        (lower_bounds.x - offset, lower_bounds.y - offset),
+        (bounds.x + offset, bounds.y + offset),
+        |(x, y)| {
+            let current = Coord { x, y };
+            coords
+                .iter()
+                .map(|coord| manhattan_distance(&current, coord))
+                .sum()
+        },
+    )
+}
+
+/// How many locations are under `max_distance` in total from every
+/// coordinate. A location's total splits into one along each axis, so this
+/// adds those up for the positions on each axis that could count and pairs
+/// them off, rather than visiting every location.
+pub fn coords_sum(coords: &[Coord], max_distance: u32) -> Result<u32> {
+    if coords.is_empty() {
+        return Err("Every location is within any distance of no coordinates".into());
+    }
+    let xs: Vec<i64> = coords.iter().map(|coord| coord.x).collect();
+    let ys: Vec<i64> = coords.iter().map(|coord| coord.y).collect();
+    let x_totals = axis_totals(&xs, max_distance);
+    let mut y_totals = axis_totals(&ys, max_distance);
+    y_totals.sort_unstable();
+    let total: u64 = x_totals
+        .iter()
+        .map(|&x_total| {
+            y_totals.partition_point(|&y_total| x_total + y_total < u64::from(max_distance)) as u64
+        })
+        .sum();
+    u32::try_from(total).map_err(|_| {
+        format!(
+            "{} locations are under the distance, more than a u32 holds",
+            total
+        )
+        .into()
+    })
+}
+
+/// The total distance along one axis from each position on it to every
+/// one of `positions`, for the positions where that is under
+/// `max_distance`. A position is at least as far from all of them as from
+/// the first, so only those within `max_distance` of it can be.
+fn axis_totals(positions: &[i64], max_distance: u32) -> Vec<u64> {
+    let first = positions[0];
+    let reach = i64::from(max_distance);
+    (first - reach..=first + reach)
+        .map(|position| {
+            positions
+                .iter()
+                .map(|&other| (position - other).unsigned_abs())
+                .sum()
+        })
+        .filter(|&total| total < u64::from(max_distance))
+        .collect()
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse_lines::<Coord>("1, 1\n1, x6").unwrap_err().to_string();
+    assert_eq!(
+        "Could not parse the input at line 2, column 4: expected a coordinate like 1, 6, found \"x6\"",
+        error
+    );
+    assert!("1, 2, 3".parse::<Coord>().is_err());
+}
+
+#[test]
+fn test_part_1() {
+    let input = r#"1, 1
+1, 6
+8, 3
+3, 4
+5, 5
+8, 9"#;
+    let input_2 = r#"0, 0
+10, 0
+9, 9"#;
+    let solution = Day6 {
+        max_distance: 10000,
+    };
+    assert_eq!(17, solution.solve_part_1(input).unwrap());
+    assert_eq!(0, solution.solve_part_1(input_2).unwrap());
+    // Past what an i32 holds, but still close together.
+    let input_3 = "2147483647, 0\n2147483649, 0";
+    assert_eq!(0, solution.solve_part_1(input_3).unwrap());
+    // Too far apart for a grid between them.
+    assert!(solution
+        .solve_part_1("0, 0\n4294967295, 4294967295")
+        .is_err());
+}
+
+#[test]
+fn test_part_2() {
+    let input = r#"1, 1
+1, 6
+8, 3
+3, 4
+5, 5
+8, 9"#;
+    let _input_2 = r#"0, 0
+10, 0
+9, 9"#;
+    let solution = Day6 { max_distance: 32 };
+    assert_eq!(16, solution.solve_part_2(input).unwrap());
+    // Every location with |x| + |y| <= 33, reaching past any fixed margin.
+    let solution = Day6 { max_distance: 34 };
+    assert_eq!(2245, solution.solve_part_2("0, 0").unwrap());
+    // assert_eq!(0, solution.solve_part_2(&input_2).unwrap());
+    let solution = Day6 { max_distance: 32 };
+    let input_3 = "2147483647, 0\n2147483649, 0";
+    assert_eq!(479, solution.solve_part_2(input_3).unwrap());
+    assert_eq!(0, solution.solve_part_2("0, 0\n4294967295, 0").unwrap());
+    // Few coordinates with the real distance reach far past them, further
+    // than a grid could hold.
+    let solution = Day6 {
+        max_distance: 10000,
+    };
+    assert_eq!(199_980_001, solution.solve_part_2("0, 0").unwrap());
+    assert_eq!(
+        12_499_972,
+        solution.solve_part_2("1, 1\n5, 5\n3, 9\n7, 2").unwrap()
+    );
+}
+
+#[test]
+fn test_render() {
+    let solution = Day6 { max_distance: 32 };
+    let coords = parse_lines("1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9").unwrap();
+    let image = solution.render(&coords).unwrap();
+    assert_eq!(((-5, -5), (14, 15)), (image.min(), image.max()));
+    assert_eq!(WHITE, image[(1, 1)]);
+    // Closest to 1, 1 alone, outside the safe region.
+    assert_eq!(color(0), image[(0, 0)]);
+    // Equally close to 1, 6 and 3, 4.
+    assert_eq!(BLACK, image[(0, 4)]);
+    // Closest to 5, 5 and 28 from them all.
+    assert_eq!(lighten(color(4)), image[(5, 4)]);
+}
+
+/// `size` distinct coordinates spread over a square that grows with `size`,
+/// about as densely as the real input.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut random = seeded(seed);
+    let side = 50 + 40 * (size as f64).sqrt() as i64;
+    let mut seen = HashSet::new();
+    let mut coords = String::new();
+    while seen.len() < size {
+        let coord = (random.random_range(0..side), random.random_range(0..side));
+        if seen.insert(coord) {
+            coords.push_str(&format!("{}, {}\n", coord.0, coord.1));
+        }
+    }
+    coords
+}
@@ -0,0 +1,295 @@
+use aoc_common::generate::{seeded, SliceRandom};
+use aoc_common::{parse_lines, parse_lines_from, Day, Error, ParseError, Result, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+    str::FromStr,
+};
+
+pub const DAY: Day = Day {
+    number: 7,
+    name: "day_7",
+    input: concat!(env!("CARGO_MANIFEST_DIR"), "/input"),
+    answers: concat!(env!("CARGO_MANIFEST_DIR"), "/answers"),
+    solution: &Day7 {
+        max_workers: 5,
+        completion_time: 60,
+    },
+    directions: concat!(env!("CARGO_MANIFEST_DIR"), "/directions"),
+    example: &Day7 {
+        max_workers: 2,
+        completion_time: 0,
+    },
+    generate,
+};
+
+pub struct Day7 {
+    pub max_workers: usize,
+    /// The extra time every step takes on top of its letter.
+    pub completion_time: u32,
+}
+
+impl Solution for Day7 {
+    type Parsed = Vec<Relationship>;
+    type Answer1 = String;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Relationship>> {
+        parse_lines(input)
+    }
+
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<Vec<Relationship>> {
+        parse_lines_from(reader, str::parse).collect()
+    }
+
+    fn part_1(&self, relationships: &Vec<Relationship>) -> Result<String> {
+        part_1(relationships)
+    }
+
+    fn part_2(&self, relationships: &Vec<Relationship>) -> Result<u32> {
+        part_2(relationships, self.max_workers, self.completion_time)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Relationship {
+    node: String,
+    require: String,
+}
+impl FromStr for Relationship {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> ::std::result::Result<Relationship, ParseError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+                r"Step (?P<require>[A-Z]) must be finished before step (?P<node>[A-Z]) can begin."
+            )
+            .unwrap();
+        }
+
+        let caps = match RE.captures(line.trim()) {
+            None => {
+                return Err(ParseError::new(
+                    line,
+                    1,
+                    "a step like \"Step C must be finished before step A can begin.\"",
+                ))
+            }
+            Some(caps) => caps,
+        };
+
+        Ok(Relationship {
+            node: caps["node"].into(),
+            require: caps["require"].into(),
+        })
+    }
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        Relationship {
+            node: "A".into(),
+            require: "C".into(),
+        },
+        "Step C must be finished before step A can begin."
+            .parse()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse_lines::<Relationship>(
+        "Step C must be finished before step A can begin.\nStep C then A",
+    )
+    .unwrap_err();
+    assert_eq!(
+        Error::Parse(
+            ParseError::new(
+                "Step C then A",
+                1,
+                "a step like \"Step C must be finished before step A can begin.\""
+            )
+            .at_line(2)
+        )
+        .to_string(),
+        error.to_string()
+    );
+}
+
+pub fn part_1(relationships: &[Relationship]) -> Result<String> {
+    // Construct are relationship mapping of Node -> Set Nodes
+    let mut map: HashMap<String, HashSet<String>> = HashMap::new();
+    for relationship in relationships {
+        map.entry(relationship.require.clone()).or_default();
+        let entry = map.entry(relationship.node.clone()).or_default();
+        entry.insert(relationship.require.clone());
+    }
+
+    let mut answer = String::new();
+    loop {
+        // Our ending condition is when we no longer have a map to pull
+        // out of
+        if map.is_empty() {
+            return Ok(answer);
+        }
+
+        // Find the head that is sorted
+        let first_node_head: Option<String> = map
+            .iter()
+            .filter(|(_, requirements)| requirements.is_empty())
+            .map(|(node, _)| node)
+            .min()
+            .cloned();
+
+        if let Some(head) = first_node_head {
+            // Add head to answer
+            answer += &head;
+            // Remove from mapping
+            map.remove(&head);
+            // Remove the head from all the other required nodes
+            map.iter_mut().for_each(|(_, requirements)| {
+                requirements.remove(&head);
+            });
+        } else {
+            return Err(cycle(&map));
+        }
+    }
+}
+
+/// The error for when none of the steps left in `map` can ever start.
+fn cycle(map: &HashMap<String, HashSet<String>>) -> Error {
+    let mut steps: Vec<&str> = map.keys().map(String::as_str).collect();
+    steps.sort();
+    format!("The steps {} all wait on each other", steps.concat()).into()
+}
+
+pub fn part_2(
+    relationships: &[Relationship],
+    max_workers: usize,
+    completion_time: u32,
+) -> Result<u32> {
+    // Construct relation map Node -> Set Node
+    let mut map: HashMap<String, HashSet<String>> = HashMap::new();
+    for relationship in relationships {
+        map.entry(relationship.require.clone()).or_default();
+        let entry = map.entry(relationship.node.clone()).or_default();
+        entry.insert(relationship.require.clone());
+    }
+
+    let mut turns = 0;
+    let mut workers: HashMap<String, u32> = HashMap::new();
+    loop {
+        // Add in all the head to open workers
+        loop {
+            if workers.len() >= max_workers {
+                break;
+            }
+            let first_node_head: Option<String> = map
+                .iter()
+                .filter(|(_, requirements)| requirements.is_empty())
+                .map(|(node, _)| node)
+                .min()
+                .cloned();
+            match first_node_head {
+                Some(head) => {
+                    map.remove(&head);
+                    workers.insert(head, turns);
+                }
+                _ => {
+                    break;
+                }
+            }
+        }
+        // Finnish Condition is when the workers are doing nothing.
+        if workers.is_empty() {
+            if !map.is_empty() {
+                return Err(cycle(&map));
+            }
+            return Ok(turns);
+        }
+        // Split our workers that are still working and those that are done
+        let (done_work, keep_working): (HashMap<_, _>, HashMap<_, _>) =
+            workers.into_iter().partition(|(head, turn)| {
+                (head.as_bytes()[0] as u32) - 65 + completion_time + *turn <= turns
+            });
+        // For the workers that are done remove the requirements from all the other nodes
+        for (head, _) in done_work {
+            map.iter_mut().for_each(|(_, requirements)| {
+                requirements.remove(&head);
+            });
+        }
+        workers = keep_working;
+        turns += 1;
+    }
+}
+
+#[test]
+fn test_cycle() {
+    let relationships: Vec<Relationship> = parse_lines(
+        "Step A must be finished before step B can begin.\n\
+         Step B must be finished before step C can begin.\n\
+         Step C must be finished before step B can begin.",
+    )
+    .unwrap();
+    assert_eq!(
+        "The steps BC all wait on each other",
+        part_1(&relationships).unwrap_err().to_string()
+    );
+    assert!(part_2(&relationships, 2, 0).is_err());
+}
+
+#[test]
+fn test_1() {
+    let input = r#"Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin."#;
+    let relationships: Vec<Relationship> = parse_lines(input).unwrap();
+    assert_eq!(&part_1(&relationships).unwrap(), &"CABDFE");
+}
+
+#[test]
+fn test_2() {
+    let input = r#"Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin."#;
+    let relationships: Vec<Relationship> = parse_lines(input).unwrap();
+    assert_eq!(part_2(&relationships, 2, 0).unwrap(), 15);
+    assert_eq!(part_2(&relationships, 3, 0).unwrap(), 14);
+    assert_eq!(part_2(&relationships, 1, 0).unwrap(), 21);
+}
+
+/// `size` requirements between the steps A to Z, at most 325 since that is
+/// every pair of steps. The steps get a random order and every requirement
+/// points forward in it, so the steps never form a cycle.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut random = seeded(seed);
+    let mut steps: Vec<char> = ('A'..='Z').collect();
+    steps.shuffle(&mut random);
+    let mut pairs: Vec<(char, char)> = (0..steps.len())
+        .flat_map(|before| ((before + 1)..steps.len()).map(move |after| (before, after)))
+        .map(|(before, after)| (steps[before], steps[after]))
+        .collect();
+    pairs.shuffle(&mut random);
+    pairs
+        .iter()
+        .take(size)
+        .map(|(require, node)| {
+            format!(
+                "Step {} must be finished before step {} can begin.\n",
+                require, node
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,173 @@
+use aoc_common::generate::{seeded, Random, Rng};
+use aoc_common::{parse_whitespace, parse_whitespace_from, Day, Result, Solution};
+use std::io::BufRead;
+
+pub const DAY: Day = Day {
+    number: 8,
+    name: "day_8",
+    input: concat!(env!("CARGO_MANIFEST_DIR"), "/input"),
+    answers: concat!(env!("CARGO_MANIFEST_DIR"), "/answers"),
+    solution: &Day8,
+    directions: concat!(env!("CARGO_MANIFEST_DIR"), "/directions"),
+    example: &Day8,
+    generate,
+};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<u32>> {
+        parse_whitespace(input, "a non-negative number")
+    }
+
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<Vec<u32>> {
+        parse_whitespace_from(reader, "a non-negative number").collect()
+    }
+
+    fn part_1(&self, numbers: &Vec<u32>) -> Result<u32> {
+        part_1(numbers)
+    }
+
+    fn part_2(&self, numbers: &Vec<u32>) -> Result<u32> {
+        part_2(numbers)
+    }
+}
+
+pub fn part_1(input: &[u32]) -> Result<u32> {
+    walk(input, |children, metadata| {
+        total(children.iter().chain(metadata).cloned())
+    })
+}
+
+#[test]
+fn t_part_1() {
+    let input = [2, 3, 0, 3, 10, 11, 12, 1, 1, 0, 1, 99, 2, 1, 1, 2];
+    assert_eq!(138, part_1(&input).unwrap());
+    assert!(part_1(&[1, 1, 0, 1, u32::MAX, 1]).is_err());
+    assert!(part_1(&[1, 1, 0, 1, 5]).is_err());
+}
+
+pub fn part_2(input: &[u32]) -> Result<u32> {
+    walk(input, |children, metadata| {
+        if children.is_empty() {
+            return total(metadata.iter().cloned());
+        }
+        total(metadata.iter().filter_map(|&i| {
+            // Metadata entries count children from 1, so 0 refers to none.
+            let child = (i as usize).checked_sub(1)?;
+            children.get(child).cloned()
+        }))
+    })
+}
+
+#[test]
+fn t_part_2() {
+    let input = [2, 3, 0, 3, 10, 11, 12, 1, 1, 0, 1, 99, 2, 1, 1, 2];
+    assert_eq!(66, part_2(&input).unwrap());
+    assert_eq!(5, part_2(&[1, 2, 0, 1, 5, 0, 1]).unwrap());
+}
+
+#[test]
+fn t_deep_tree() {
+    // A chain of nodes a million deep, each pointing at its only child.
+    let depth = 1_000_000;
+    let mut input = [1, 1].repeat(depth);
+    input.extend_from_slice(&[0, 1, 7]);
+    input.extend(std::iter::repeat_n(1, depth));
+    assert_eq!(7 + depth as u32, part_1(&input).unwrap());
+    assert_eq!(7, part_2(&input).unwrap());
+}
+
+/// A node whose header has been read but whose children are still coming.
+struct Node {
+    children_left: u32,
+    meta_count: usize,
+    children: Vec<u32>,
+}
+
+/// Read the node at the front of `input`, working out each node's value
+/// from its children's values and its metadata. The walk keeps its own
+/// stack, so however deep the tree goes it can't overflow the real one.
+fn walk(input: &[u32], value: fn(&[u32], &[u32]) -> Result<u32>) -> Result<u32> {
+    let mut at = 0;
+    let mut stack = vec![header(input, &mut at)?];
+    while let Some(node) = stack.last_mut() {
+        if node.children_left > 0 {
+            node.children_left -= 1;
+            let child = header(input, &mut at)?;
+            stack.push(child);
+            continue;
+        }
+        let node = stack.pop().expect("The stack was just looked at");
+        let metadata = input.get(at..at + node.meta_count).ok_or_else(|| {
+            format!(
+                "Input={:?} is not long enough for {} metadata entries",
+                &input[at..],
+                node.meta_count
+            )
+        })?;
+        at += node.meta_count;
+        let node_value = value(&node.children, metadata)?;
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(node_value),
+            None => return Ok(node_value),
+        }
+    }
+    unreachable!("The walk returns once the root node is done")
+}
+
+fn header(input: &[u32], at: &mut usize) -> Result<Node> {
+    match input.get(*at..*at + 2) {
+        Some(&[children_left, meta_count]) => {
+            *at += 2;
+            Ok(Node {
+                children_left,
+                meta_count: meta_count as usize,
+                children: vec![],
+            })
+        }
+        _ => Err(format!(
+            "Don't have enough for getting the headers out: {:?}",
+            &input[*at..]
+        )
+        .into()),
+    }
+}
+
+/// Add up `values`, failing rather than wrapping past `u32::MAX`.
+fn total(mut values: impl Iterator<Item = u32>) -> Result<u32> {
+    values
+        .try_fold(0u32, u32::checked_add)
+        .ok_or_else(|| "The total does not fit in a u32".into())
+}
+
+/// A license tree of `size` nodes, each with one to five metadata entries
+/// from 1 to 9.
+pub fn generate(seed: u64, size: usize) -> String {
+    fn write(node: usize, children: &[Vec<usize>], random: &mut Random, out: &mut Vec<String>) {
+        let meta_count = random.random_range(1..=5);
+        out.push(children[node].len().to_string());
+        out.push(meta_count.to_string());
+        for &child in &children[node] {
+            write(child, children, random, out);
+        }
+        for _ in 0..meta_count {
+            out.push(random.random_range(1..=9).to_string());
+        }
+    }
+
+    let mut random = seeded(seed);
+    // Each node hangs off a random earlier one, which keeps the tree shallow.
+    let mut children = vec![vec![]; size.max(1)];
+    for node in 1..children.len() {
+        let parent = random.random_range(0..node);
+        children[parent].push(node);
+    }
+    let mut out = vec![];
+    write(0, &children, &mut random, &mut out);
+    out.join(" ") + "\n"
+}
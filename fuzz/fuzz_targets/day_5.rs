@@ -0,0 +1,8 @@
+#![no_main]
+//! The polymer and both parts.
+
+use aoc_common::Part;
+use aoc_fuzz::solve;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| solve(day_5::DAY.solution, &Part::ALL, input));
@@ -0,0 +1,10 @@
+#![no_main]
+//! `Coord::from_str` and both parts, with the puzzle's own safe distance.
+//! Coordinates too far apart for part 1's grid are turned down by its area
+//! limit.
+
+use aoc_common::Part;
+use aoc_fuzz::solve;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| solve(day_6::DAY.solution, &Part::ALL, input));
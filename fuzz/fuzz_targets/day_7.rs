@@ -0,0 +1,8 @@
+#![no_main]
+//! `Relationship::from_str` and both parts.
+
+use aoc_common::Part;
+use aoc_fuzz::solve;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| solve(day_7::DAY.solution, &Part::ALL, input));
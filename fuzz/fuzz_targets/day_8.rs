@@ -0,0 +1,8 @@
+#![no_main]
+//! day_8's whitespace separated number parser and both parts.
+
+use aoc_common::Part;
+use aoc_fuzz::solve;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| solve(day_8::DAY.solution, &Part::ALL, input));
@@ -0,0 +1,8 @@
+#![no_main]
+//! The box id list and both parts.
+
+use aoc_common::Part;
+use aoc_fuzz::solve;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| solve(problem_2::DAY.solution, &Part::ALL, input));
@@ -0,0 +1,9 @@
+#![no_main]
+//! `claims_from_input` and both parts. Fabric bigger than the grid's area
+//! limit is turned down rather than allocated.
+
+use aoc_common::Part;
+use aoc_fuzz::solve;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| solve(problem_3::DAY.solution, &Part::ALL, input));
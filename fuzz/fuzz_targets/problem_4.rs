@@ -0,0 +1,8 @@
+#![no_main]
+//! `Event::from_str`, `hash_event_times` and both parts.
+
+use aoc_common::Part;
+use aoc_fuzz::solve;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| solve(problem_4::DAY.solution, &Part::ALL, input));
@@ -0,0 +1,16 @@
+//! What every fuzz target does with its input: parse it the way a day does
+//! and, when that works, answer the parts from it. Errors are fine; only a
+//! panic, a hang or running out of memory is a failure.
+//!
+//! Seed the corpus from the committed inputs with `cargo run -p aoc --
+//! corpus`, then fuzz a day with `cargo fuzz run day_7`.
+
+use aoc_common::{AnySolution, Part};
+
+pub fn solve(solution: &dyn AnySolution, parts: &[Part], input: &str) {
+    if let Ok(parsed) = solution.parse_any(input) {
+        for &part in parts {
+            let _ = solution.part_any(part, &*parsed);
+        }
+    }
+}
@@ -0,0 +1,638 @@
+use aoc_common::generate::{seeded, Rng};
+use aoc_common::{parse_list_from, Day, Error, Result, Solution};
+use im::HashSet;
+use num_bigint::BigInt;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::convert::TryFrom;
+use std::fmt::{self, Write};
+use std::hash::Hash;
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub const DAY: Day = Day {
+    number: 1,
+    name: "problem1",
+    input: concat!(env!("CARGO_MANIFEST_DIR"), "/input1"),
+    answers: concat!(env!("CARGO_MANIFEST_DIR"), "/answers"),
+    solution: &Problem1,
+    directions: concat!(env!("CARGO_MANIFEST_DIR"), "/directions.md"),
+    example: &Problem1,
+    generate,
+};
+
+pub struct Problem1;
+
+impl Solution for Problem1 {
+    type Parsed = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>> {
+        self.parse_reader(&mut input.as_bytes())
+    }
+
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<Vec<i64>> {
+        parse_changes(reader)
+    }
+
+    fn part_1(&self, changes: &Vec<i64>) -> Result<i64> {
+        part_1(changes)
+    }
+
+    fn part_2(&self, changes: &Vec<i64>) -> Result<i64> {
+        part_2(changes)
+    }
+}
+
+/// Read the frequency changes, as whatever integer they are to be added up
+/// in. They can be one per line like the puzzle input or written out like
+/// the directions do, "+1, -2, +3, +1", with or without the `+`.
+pub fn parse_changes<F: Frequency>(reader: &mut dyn BufRead) -> Result<Vec<F>> {
+    parse_list_from(reader, "a frequency change like +7 or -3").collect()
+}
+
+/// An integer a frequency can be kept in. Adding to one that can't hold the
+/// total is an error rather than wrapping round.
+pub trait Frequency: Clone + Eq + Hash + FromStr + fmt::Debug + fmt::Display {
+    /// What it is called in the error when a total doesn't fit.
+    const NAME: &'static str;
+
+    fn zero() -> Self;
+
+    fn checked_add(&self, change: &Self) -> Option<Self>;
+}
+
+macro_rules! fixed_width_frequency {
+    ($($integer:ident),*) => {
+        $(
+            impl Frequency for $integer {
+                const NAME: &'static str = stringify!($integer);
+
+                fn zero() -> $integer {
+                    0
+                }
+
+                fn checked_add(&self, change: &$integer) -> Option<$integer> {
+                    $integer::checked_add(*self, *change)
+                }
+            }
+        )*
+    };
+}
+
+fixed_width_frequency!(i32, i64, i128);
+
+impl Frequency for BigInt {
+    const NAME: &'static str = "BigInt";
+
+    fn zero() -> BigInt {
+        BigInt::default()
+    }
+
+    fn checked_add(&self, change: &BigInt) -> Option<BigInt> {
+        Some(self + change)
+    }
+}
+
+/// The device's frequency as changes are applied one at a time, along with
+/// every frequency it has been at, so either answer is there whenever it's
+/// asked for however the changes arrive.
+#[derive(Debug, Clone)]
+pub struct FrequencyTracker<F: Frequency = i64> {
+    current: F,
+    seen: HashSet<F>,
+    first_repeat: Option<F>,
+}
+
+impl<F: Frequency> FrequencyTracker<F> {
+    /// A tracker at the starting frequency of 0, which counts as reached.
+    pub fn new() -> FrequencyTracker<F> {
+        FrequencyTracker {
+            current: F::zero(),
+            seen: HashSet::unit(F::zero()),
+            first_repeat: None,
+        }
+    }
+
+    /// Apply `change`, returning the new frequency. A frequency past what
+    /// an `F` holds is an error and leaves the tracker as it was.
+    pub fn apply(&mut self, change: &F) -> Result<&F> {
+        self.current = self.current.checked_add(change).ok_or_else(too_big::<F>)?;
+        if self.seen.insert(self.current.clone()).is_some() && self.first_repeat.is_none() {
+            self.first_repeat = Some(self.current.clone());
+        }
+        Ok(&self.current)
+    }
+
+    pub fn current(&self) -> &F {
+        &self.current
+    }
+
+    /// The first frequency reached a second time so far.
+    pub fn first_repeat(&self) -> Option<&F> {
+        self.first_repeat.as_ref()
+    }
+
+    /// How many different frequencies have been reached so far.
+    pub fn seen_count(&self) -> usize {
+        self.seen.len()
+    }
+}
+
+impl<F: Frequency> Default for FrequencyTracker<F> {
+    fn default() -> FrequencyTracker<F> {
+        FrequencyTracker::new()
+    }
+}
+
+pub fn part_1<F: Frequency>(changes: &[F]) -> Result<F> {
+    changes
+        .iter()
+        .try_fold(F::zero(), |total, change| total.checked_add(change))
+        .ok_or_else(too_big::<F>)
+}
+
+fn too_big<F: Frequency>() -> Error {
+    format!("The frequency goes past what an {} holds", F::NAME).into()
+}
+
+/// The first frequency reached twice while the changes are applied over
+/// and over, counting the starting frequency of 0 as reached, or an error
+/// when there is none.
+pub fn part_2(changes: &[i64]) -> Result<i64> {
+    first_repeat(changes)?.ok_or_else(|| NEVER_REPEATS.into())
+}
+
+/// The first frequency reached twice, if any ever is.
+///
+/// Rather than going round until something repeats, which never ends when
+/// nothing does, this looks at the frequencies before each change of the
+/// first pass. Each pass shifts them all by the drift, the total of the
+/// changes, so two of them only ever meet when they are the same modulo
+/// the drift, and then the one behind catches up with the nearest one ahead
+/// in the direction of the drift after as many passes as the drift divides
+/// their distance.
+fn first_repeat(changes: &[i64]) -> Result<Option<i64>> {
+    let mut frequencies = Vec::with_capacity(changes.len());
+    let mut tracker = FrequencyTracker::new();
+    for change in changes {
+        frequencies.push(*tracker.current());
+        tracker.apply(change)?;
+        if let Some(&frequency) = tracker.first_repeat() {
+            return Ok(Some(frequency));
+        }
+    }
+    // Past here the frequencies of the first pass are all different, so
+    // without drift they can't ever repeat.
+    let drift = i128::from(*tracker.current());
+    if drift == 0 {
+        return Ok(None);
+    }
+
+    let mut order: Vec<usize> = (0..frequencies.len()).collect();
+    order.sort_by_key(|&i| {
+        let frequency = i128::from(frequencies[i]);
+        (frequency.rem_euclid(drift), frequency * drift.signum())
+    });
+    let passes = frequencies.len() as u128;
+    let mut first: Option<(u128, i64)> = None;
+    for pair in order.windows(2) {
+        let (behind, ahead) = (i128::from(frequencies[pair[0]]), frequencies[pair[1]]);
+        let distance = i128::from(ahead) - behind;
+        if distance % drift != 0 {
+            continue;
+        }
+        // Counted in changes applied, so the earliest repeat wins whatever
+        // pass it happens in.
+        let reached = pair[0] as u128 + passes * (distance / drift) as u128;
+        if first.is_none_or(|(earliest, _)| reached < earliest) {
+            first = Some((reached, ahead));
+        }
+    }
+    Ok(first.map(|(_, frequency)| frequency))
+}
+
+const NEVER_REPEATS: &str = "The frequency never repeats, however often the changes are applied";
+
+/// A time the device was at some frequency: after the change at index
+/// `line` of the list, counting from 0, in pass `cycle`, or at the start
+/// before any change when there is no `line`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Reach {
+    pub cycle: u64,
+    pub line: Option<usize>,
+}
+
+impl fmt::Display for Reach {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}", self.cycle, line),
+            None => write!(f, "start"),
+        }
+    }
+}
+
+/// A frequency reached more than once, with when it was first reached and
+/// every time after that.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Repeat {
+    pub frequency: i64,
+    pub first: Reach,
+    pub again: Vec<Reach>,
+}
+
+/// The first `limit` frequencies to be reached a second time while the
+/// changes are applied over and over, in the order that happens.
+///
+/// With drift, the total of the changes, no frequency comes round more than
+/// once per change in the list, and each is listed with every time it is
+/// reached. Without drift every frequency of the first pass comes round
+/// again in every pass, so only the first two passes are listed.
+pub fn repeats(changes: &[i64], limit: usize) -> Result<Vec<Repeat>> {
+    // This also turns down a first pass going past what an i64 holds, and
+    // no changes at all.
+    if limit == 0 || first_repeat(changes)?.is_none() {
+        return Ok(vec![]);
+    }
+    // The frequencies before each change of the first pass, from which every
+    // later one is a whole number of drifts away.
+    let mut before = Vec::with_capacity(changes.len());
+    let mut frequency: i128 = 0;
+    for change in changes {
+        before.push(frequency);
+        frequency += i128::from(*change);
+    }
+    let drift = frequency;
+    if drift == 0 {
+        return Ok(repeats_without_drift(&before, limit));
+    }
+
+    // As in `first_repeat`, a frequency is only ever reached from the ones
+    // behind it with the same remainder, each after as many passes as the
+    // drift divides their distance, and the nearest gets there first. Lined
+    // up that way, with the earlier of two equal frequencies nearer, the
+    // frequencies from one up to the next are reached a second time from
+    // the one behind it, a pass apart. So each neighbouring pair gives an
+    // ordered stream of repeats and merging those gives them all in order.
+    let mut order: Vec<usize> = (0..before.len()).collect();
+    order.sort_by_key(|&i| {
+        let frequency = before[i];
+        (
+            frequency.rem_euclid(drift),
+            frequency * drift.signum(),
+            Reverse(i),
+        )
+    });
+    let same_run = |a: usize, b: usize| {
+        before[order[a]].rem_euclid(drift) == before[order[b]].rem_euclid(drift)
+    };
+    let mut run_starts = Vec::with_capacity(order.len());
+    for at in 0..order.len() {
+        let run_start = if at > 0 && same_run(at - 1, at) {
+            run_starts[at - 1]
+        } else {
+            at
+        };
+        run_starts.push(run_start);
+    }
+    let passes = before.len() as u128;
+    let drifts = |from: usize, to: usize| ((before[to] - before[from]) / drift) as u128;
+    // Whether the pair ending at `at` repeats a frequency `step` drifts past
+    // the nearer, which is short of the next one ahead if there is one.
+    let has_step = |at: usize, step: u128| {
+        at + 1 == order.len() || !same_run(at, at + 1) || step < drifts(order[at], order[at + 1])
+    };
+
+    // Ordered by when the repeat happens, counted in changes applied, then
+    // which pair it comes from and how many drifts past the nearer it is.
+    let mut streams = BinaryHeap::new();
+    for at in 1..order.len() {
+        if same_run(at - 1, at) && has_step(at, 0) {
+            let reached = order[at - 1] as u128 + passes * drifts(order[at - 1], order[at]);
+            streams.push(Reverse((reached, at, 0u128)));
+        }
+    }
+
+    let mut found = vec![];
+    while found.len() < limit {
+        let Reverse((reached, at, step)) = match streams.pop() {
+            Some(next) => next,
+            None => break,
+        };
+        if has_step(at, step + 1) {
+            streams.push(Reverse((reached + passes, at, step + 1)));
+        }
+
+        let frequency = before[order[at]] + drift * step as i128;
+        let frequency =
+            i64::try_from(frequency).map_err(|_| "The frequency goes past what an i64 holds")?;
+        let mut reaches = (run_starts[at]..=at).rev().map(|behind| {
+            let point = order[behind];
+            let cycles = ((i128::from(frequency) - before[point]) / drift) as u128;
+            reach_at(point as u128 + passes * cycles, passes)
+        });
+        found.push(Repeat {
+            frequency,
+            first: reaches.next().expect("A frequency is reached from itself"),
+            again: reaches.collect(),
+        });
+    }
+    Ok(found)
+}
+
+/// The repeats over the first two passes of changes that add up to nothing,
+/// given the frequencies before each change.
+fn repeats_without_drift(before: &[i128], limit: usize) -> Vec<Repeat> {
+    let passes = before.len() as u128;
+    let mut seen = HashSet::new();
+    let mut found = vec![];
+    // Counted in changes applied, where the frequency before the first
+    // change of the third pass is where the first two end up.
+    for reached in 0..=2 * passes {
+        let frequency = before[(reached % passes) as usize];
+        if seen.insert(frequency).is_some() && !found.contains(&frequency) {
+            found.push(frequency);
+        }
+    }
+    found
+        .into_iter()
+        .take(limit)
+        .map(|frequency| {
+            let mut reaches = (0..=2 * passes)
+                .filter(|&reached| before[(reached % passes) as usize] == frequency)
+                .map(|reached| reach_at(reached, passes));
+            Repeat {
+                frequency: frequency as i64,
+                first: reaches.next().expect("A repeat is reached at least twice"),
+                again: reaches.collect(),
+            }
+        })
+        .collect()
+}
+
+/// When the device is after `reached` changes, with `passes` in a pass.
+fn reach_at(reached: u128, passes: u128) -> Reach {
+    match reached.checked_sub(1) {
+        Some(applied) => Reach {
+            cycle: (applied / passes) as u64,
+            line: Some((applied % passes) as usize),
+        },
+        None => Reach {
+            cycle: 0,
+            line: None,
+        },
+    }
+}
+
+/// Lay the repeats out as a table, one row per frequency, with each time
+/// it was reached as `cycle:line`.
+pub fn repeats_table(repeats: &[Repeat]) -> String {
+    let rows: Vec<[String; 3]> = repeats
+        .iter()
+        .map(|repeat| {
+            let again: Vec<String> = repeat.again.iter().map(Reach::to_string).collect();
+            [
+                repeat.frequency.to_string(),
+                repeat.first.to_string(),
+                again.join(" "),
+            ]
+        })
+        .collect();
+    let header = [
+        "Frequency".to_string(),
+        "First".to_string(),
+        "Again".to_string(),
+    ];
+    let width = |column: usize| {
+        rows.iter()
+            .map(|row| row[column].len())
+            .fold(header[column].len(), usize::max)
+    };
+    let (frequency_width, first_width) = (width(0), width(1));
+
+    let mut table = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let _ = writeln!(
+            table,
+            "{:>fw$} | {:>rw$} | {}",
+            row[0],
+            row[1],
+            row[2],
+            fw = frequency_width,
+            rw = first_width
+        );
+    }
+    table
+}
+
+/// The repeats as a JSON array, one object per frequency, with the start
+/// shown as a `line` of null.
+pub fn repeats_json(repeats: &[Repeat]) -> String {
+    let mut json = serde_json::to_string_pretty(repeats).unwrap();
+    json.push('\n');
+    json
+}
+
+/// `size` frequency changes that drift by less than `size` per pass, which
+/// guarantees some frequency is reached twice.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut random = seeded(seed);
+    let size = size.max(1);
+    let mut changes: Vec<i64> = (1..size)
+        .map(|_| random.random_range(1..=20) * if random.random_bool(0.5) { 1 } else { -1 })
+        .collect();
+    let drift = if size > 1 {
+        random.random_range(1..size as i64) * if random.random_bool(0.5) { 1 } else { -1 }
+    } else {
+        0
+    };
+    changes.push(drift - changes.iter().sum::<i64>());
+    changes
+        .iter()
+        .map(|change| format!("{:+}\n", change))
+        .collect()
+}
+
+#[test]
+fn test_part_1() {
+    assert_eq!(3, part_1(&[1, -2, 3, 1]).unwrap());
+    assert!(part_1(&[i32::MAX, 1]).is_err());
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(2, part_2(&[1, -2, 3, 1]).unwrap());
+    assert_eq!(0, part_2(&[1, -1]).unwrap());
+    assert_eq!(10, part_2(&[3, 3, 4, -2, -4]).unwrap());
+    assert_eq!(5, part_2(&[-6, 3, 8, 5, -6]).unwrap());
+    assert_eq!(14, part_2(&[7, 7, -2, -7, -4]).unwrap());
+    assert_eq!(-1, part_2(&[-1, 2, -2]).unwrap());
+    assert_eq!(1_000_000, part_2(&[1_000_000, -999_999]).unwrap());
+    assert_eq!(NEVER_REPEATS, part_2(&[1, 1]).unwrap_err().to_string());
+    assert!(part_2(&[3, -1]).is_err());
+    assert!(part_2(&[-3, 1]).is_err());
+    assert!(part_2(&[]).is_err());
+}
+
+#[test]
+fn test_tracker() {
+    let mut tracker = FrequencyTracker::<i64>::new();
+    let state = |tracker: &FrequencyTracker| {
+        (
+            *tracker.current(),
+            tracker.first_repeat().copied(),
+            tracker.seen_count(),
+        )
+    };
+    assert_eq!((0, None, 1), state(&tracker));
+    for change in &[1, -2, 3, 1] {
+        tracker.apply(change).unwrap();
+    }
+    assert_eq!((3, None, 5), state(&tracker));
+    assert_eq!(&4, tracker.apply(&1).unwrap());
+    assert_eq!(&2, tracker.apply(&-2).unwrap());
+    assert_eq!(Some(&2), tracker.first_repeat());
+    tracker.apply(&-2).unwrap();
+    assert_eq!((0, Some(2), 6), state(&tracker));
+
+    tracker.apply(&i64::MAX).unwrap();
+    assert_eq!(
+        "The frequency goes past what an i64 holds",
+        tracker.apply(&1).unwrap_err().to_string()
+    );
+    assert_eq!(&i64::MAX, tracker.current());
+}
+
+#[test]
+fn test_wide_frequencies() {
+    let input = "+170141183460469231731687303715884105727\n+1\n-1\n";
+    assert!(parse_changes::<i64>(&mut input.as_bytes()).is_err());
+
+    let changes: Vec<i128> = parse_changes(&mut input.as_bytes()).unwrap();
+    assert_eq!(
+        "The frequency goes past what an i128 holds",
+        part_1(&changes).unwrap_err().to_string()
+    );
+
+    let changes: Vec<BigInt> = parse_changes(&mut input.as_bytes()).unwrap();
+    assert_eq!(
+        "170141183460469231731687303715884105727",
+        part_1(&changes).unwrap().to_string()
+    );
+    let mut tracker = FrequencyTracker::new();
+    for change in &changes {
+        tracker.apply(change).unwrap();
+    }
+    assert_eq!(
+        "170141183460469231731687303715884105727",
+        tracker.first_repeat().unwrap().to_string()
+    );
+    // 0, then one past what an i128 holds on the way back.
+    assert_eq!(3, tracker.seen_count());
+}
+
+#[test]
+fn test_repeats() {
+    let reach = |cycle, line| Reach {
+        cycle,
+        line: Some(line),
+    };
+    let found = repeats(&[1, -2, 3, 1], 3).unwrap();
+    assert_eq!(
+        vec![
+            Repeat {
+                frequency: 2,
+                first: reach(0, 2),
+                again: vec![reach(1, 1)],
+            },
+            Repeat {
+                frequency: 5,
+                first: reach(1, 2),
+                again: vec![reach(2, 1)],
+            },
+            Repeat {
+                frequency: 8,
+                first: reach(2, 2),
+                again: vec![reach(3, 1)],
+            },
+        ],
+        found
+    );
+    assert_eq!(
+        "Frequency | First | Again\n        2 |   0:2 | 1:1\n        5 |   1:2 | 2:1\n        8 |   2:2 | 3:1\n",
+        repeats_table(&found)
+    );
+    let start = Reach {
+        cycle: 0,
+        line: None,
+    };
+    assert_eq!(
+        vec![
+            Repeat {
+                frequency: 0,
+                first: start,
+                again: vec![reach(1, 0), reach(2, 1)],
+            },
+            Repeat {
+                frequency: 1,
+                first: reach(0, 0),
+                again: vec![reach(1, 1)],
+            },
+        ],
+        repeats(&[1, 1, -3], 2).unwrap()
+    );
+
+    // Without drift the first two passes show everything, however many
+    // repeats are asked for.
+    let found = repeats(&[1, -1], 10).unwrap();
+    assert_eq!(2, found.len());
+    assert_eq!(
+        (
+            0,
+            Reach {
+                cycle: 0,
+                line: None
+            }
+        ),
+        (found[0].frequency, found[0].first)
+    );
+    assert_eq!(vec![reach(0, 1), reach(1, 1)], found[0].again);
+    assert_eq!(1, found[1].frequency);
+    assert!(repeats(&[1, 1], 10).unwrap().is_empty());
+    assert!(repeats(&[1, -2, 3, 1], 0).unwrap().is_empty());
+    // The first repeat is a billion passes in.
+    assert_eq!(
+        vec![Repeat {
+            frequency: 1_000_000_000,
+            first: reach(0, 0),
+            again: vec![reach(999_999_999, 1)],
+        }],
+        repeats(&[1_000_000_000, -999_999_999], 1).unwrap()
+    );
+    let changes = [i64::MAX, 1 - i64::MAX];
+    assert_eq!(i64::MAX, repeats(&changes, 1).unwrap()[0].frequency);
+    assert_eq!(
+        "The frequency goes past what an i64 holds",
+        repeats(&changes, 2).unwrap_err().to_string()
+    );
+    assert_eq!(
+        serde_json::json!([{
+            "frequency": 0,
+            "first": { "cycle": 0, "line": null },
+            "again": [{ "cycle": 0, "line": 1 }, { "cycle": 1, "line": 1 }]
+        }]),
+        serde_json::from_str::<serde_json::Value>(&repeats_json(&found[..1])).unwrap()
+    );
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(vec![1, -2, 3, 1], Problem1.parse("+1, -2, +3, +1").unwrap());
+    assert_eq!(vec![7, -3, 2], Problem1.parse("+7\n\n-3\n2\n").unwrap());
+    assert_eq!(
+        "Could not parse the input at line 3, column 5: expected a frequency change like +7 or -3, found \"++2\"",
+        Problem1.parse("+1\n-1\n+3, ++2").unwrap_err().to_string()
+    );
+}
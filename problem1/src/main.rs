@@ -1,50 +1,46 @@
-extern crate hashbrown;
-extern crate im;
+use aoc_common::{open_input, Result, Solution};
+use problem1::{repeats, repeats_json, repeats_table, Problem1, DAY};
+use std::env;
+use std::process;
 
-use im::HashSet;
-use std::fs::File;
-use std::io::prelude::*;
-use std::io::BufReader;
+const USAGE: &str = "usage: problem1 [input] [--repeats <limit> [--format <table|json>]]";
 
-type Result<T> = ::std::result::Result<T, Box<::std::error::Error>>;
-fn input() -> Result<String> {
-    let file = File::open("input1")?;
-    let mut buf_reader = BufReader::new(file);
-    let mut contents = String::new();
-    buf_reader.read_to_string(&mut contents)?;
-    Ok(contents)
-}
-
-fn part_1(input: &String) -> Result<i32> {
-    let mut amount = 0;
-    for line in input.lines() {
-        let value: i32 = line.parse()?;
-        amount += value;
-    }
-    Ok(amount)
-}
-
-fn part_2(input: &String) -> Result<i32> {
-    let mut amount = 0;
-    let mut hash_set: HashSet<i32> = HashSet::new();
-    loop {
-        for line in input.lines() {
-            let value: i32 = line.parse()?;
-            amount += value;
-            if hash_set.contains(&amount) {
-                return Ok(amount);
+fn main() -> Result<()> {
+    let mut input_path = DAY.input.to_string();
+    let mut limit = None;
+    let mut format = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--repeats" => {
+                let limit_arg = args.next().and_then(|limit| limit.parse().ok());
+                limit = Some(limit_arg.unwrap_or_else(|| usage()));
             }
-            hash_set.insert(amount);
+            "--format" => format = Some(args.next().unwrap_or_else(|| usage())),
+            _ => input_path = arg,
         }
     }
-}
 
-fn main() -> Result<()> {
-    let input_read = input()?;
-    let answer_part_1 = part_1(&input_read)?;
-    let answer_part_2 = part_2(&input_read)?;
+    let mut input = open_input(input_path)?;
+    let changes = Problem1.parse_reader(&mut input)?;
+    match (limit, format.as_deref()) {
+        (None, None) => {
+            let answer_part_1 = Problem1.part_1(&changes)?;
+            let answer_part_2 = Problem1.part_2(&changes)?;
 
-    println!("Answer for part 1 is {}", answer_part_1);
-    println!("Answer for part 2 is {}", answer_part_2);
+            println!("Answer for part 1 is {}", answer_part_1);
+            println!("Answer for part 2 is {}", answer_part_2);
+        }
+        (Some(limit), None) | (Some(limit), Some("table")) => {
+            print!("{}", repeats_table(&repeats(&changes, limit)?));
+        }
+        (Some(limit), Some("json")) => print!("{}", repeats_json(&repeats(&changes, limit)?)),
+        _ => usage(),
+    }
     Ok(())
 }
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
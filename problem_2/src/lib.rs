@@ -0,0 +1,145 @@
+use aoc_common::generate::{seeded, IndexedRandom, Rng};
+use aoc_common::{parse_lines_from, Day, Result, Solution};
+use im::{HashMap, HashSet};
+use std::io::BufRead;
+
+pub const DAY: Day = Day {
+    number: 2,
+    name: "problem_2",
+    input: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/part1"),
+    answers: concat!(env!("CARGO_MANIFEST_DIR"), "/answers"),
+    solution: &Problem2,
+    directions: concat!(env!("CARGO_MANIFEST_DIR"), "/directions.md"),
+    example: &Problem2,
+    generate,
+};
+
+pub struct Problem2;
+
+impl Solution for Problem2 {
+    type Parsed = Vec<String>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(|line| line.trim().to_string()).collect())
+    }
+
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<Vec<String>> {
+        parse_lines_from(reader, |line| Ok(line.trim().to_string())).collect()
+    }
+
+    fn part_1(&self, ids: &Vec<String>) -> Result<i32> {
+        Ok(part_1(ids))
+    }
+
+    fn part_2(&self, ids: &Vec<String>) -> Result<String> {
+        Ok(part_2(ids))
+    }
+}
+
+fn hash_part(line: &str) -> (i32, i32) {
+    let mut hash_map: HashMap<char, i32> = HashMap::new();
+
+    for c in line.chars() {
+        hash_map
+            .entry(c)
+            .and_modify(|x| {
+                *x += 1;
+            })
+            .or_insert(1);
+    }
+    (
+        hash_map.values().filter(|&&value| value == 2).count() as i32,
+        hash_map.values().filter(|&&value| value == 3).count() as i32,
+    )
+}
+
+#[test]
+fn test_hash_part() {
+    assert_eq!((0, 0), hash_part("abcdef"));
+    assert_eq!((1, 1), hash_part("bababc"));
+    assert_eq!((1, 0), hash_part("abbcde"));
+    assert_eq!((0, 1), hash_part("abcccd"));
+    assert_eq!((2, 0), hash_part("aabcdd"));
+    assert_eq!((1, 0), hash_part("abcdee"));
+    assert_eq!((0, 2), hash_part("ababab"));
+}
+
+pub fn part_1(ids: &[String]) -> i32 {
+    let mut twos = 0;
+    let mut threes = 0;
+    for line in ids {
+        let hashed = hash_part(line);
+        twos += match hashed.0 {
+            0 => 0,
+            _ => 1,
+        };
+        threes += match hashed.1 {
+            0 => 0,
+            _ => 1,
+        };
+    }
+    twos * threes
+}
+#[test]
+fn test_part_1() {
+    assert_eq!(
+        12,
+        Problem2
+            .solve_part_1("abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab")
+            .unwrap()
+    );
+}
+
+pub fn part_2(ids: &[String]) -> String {
+    let mut seen: HashMap<usize, HashMap<char, HashSet<String>>> = HashMap::new();
+    let mut winner: String = String::new();
+
+    for line in ids {
+        // Closeness
+        let mut closeness_hash: HashMap<String, String> = HashMap::new();
+        for (i, c) in line.chars().enumerate() {
+            let local_seen = seen.entry(i).or_default();
+            let local_seen_set = local_seen.entry(c).or_default();
+            for found_value in local_seen_set.iter() {
+                let current_matches = closeness_hash.entry(found_value.to_string()).or_default();
+                current_matches.push(c);
+            }
+            local_seen_set.insert(line.to_string());
+        }
+
+        for (_key, value) in closeness_hash {
+            if value.len() > winner.len() {
+                winner = value.to_string();
+            }
+        }
+    }
+
+    winner
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(
+        "fgij",
+        Problem2
+            .solve_part_2("abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz")
+            .unwrap()
+    );
+}
+
+/// `size` box ids of 26 letters, exactly two of which differ by one letter.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut random = seeded(seed);
+    let mut ids: Vec<Vec<u8>> = (0..size.max(2) - 1)
+        .map(|_| (0..26).map(|_| random.random_range(b'a'..=b'z')).collect())
+        .collect();
+    let mut twin = ids.choose(&mut random).unwrap().clone();
+    let letter = random.random_range(0..twin.len());
+    twin[letter] = (twin[letter] - b'a' + random.random_range(1..26)) % 26 + b'a';
+    ids.insert(random.random_range(0..=ids.len()), twin);
+    ids.into_iter()
+        .map(|id| String::from_utf8(id).unwrap() + "\n")
+        .collect()
+}
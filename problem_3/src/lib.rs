@@ -0,0 +1,299 @@
+use aoc_common::generate::{seeded, Random, Rng, SliceRandom};
+use aoc_common::image::heat;
+use aoc_common::{
+    parse_lines, parse_lines_from, parse_number, Day, Grid, Image, ParseError, Point, Result,
+    Solution,
+};
+use im::hashset::HashSet;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub const DAY: Day = Day {
+    number: 3,
+    name: "problem_3",
+    input: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/part_1"),
+    answers: concat!(env!("CARGO_MANIFEST_DIR"), "/answers"),
+    solution: &Problem3,
+    directions: concat!(env!("CARGO_MANIFEST_DIR"), "/instructions.md"),
+    example: &Problem3,
+    generate,
+};
+
+pub struct Problem3;
+
+impl Solution for Problem3 {
+    type Parsed = Vec<Claim>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Claim>> {
+        claims_from_input(input)
+    }
+
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<Vec<Claim>> {
+        parse_lines_from(reader, str::parse).collect()
+    }
+
+    fn part_1(&self, claims: &Vec<Claim>) -> Result<u32> {
+        part_1(claims)
+    }
+
+    fn part_2(&self, claims: &Vec<Claim>) -> Result<u32> {
+        part_2(claims)
+    }
+
+    /// How many claims cover each square inch, brighter where more overlap.
+    fn render(&self, claims: &Vec<Claim>) -> Result<Image> {
+        let counts = claim_counts(claims)?;
+        let most = counts.values().cloned().max().unwrap_or(0);
+        Grid::from_fn(counts.min(), counts.max(), |point| {
+            heat(counts[point], most)
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Claim {
+    id: u32,
+    offset_x: u32,
+    offset_y: u32,
+    size_x: u32,
+    size_y: u32,
+}
+
+impl FromStr for Claim {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> ::std::result::Result<Claim, ParseError> {
+        const EXPECTED: &str = "a claim like #<id> @ <x>,<y>: <width>x<height>";
+        lazy_static! {
+            static ref regex: Regex = Regex::new(r"#(\d+) @ (\d+),(\d+): (\d+)x(\d+)").unwrap();
+        }
+        let capture = regex
+            .captures(line)
+            .ok_or_else(|| ParseError::new(line, 1, EXPECTED))?;
+        let field = |i: usize| {
+            let found = capture.get(i).unwrap();
+            parse_number::<u32>(found.as_str(), found.start() + 1, EXPECTED)
+        };
+        let claim = Claim {
+            id: field(1)?,
+            offset_x: field(2)?,
+            offset_y: field(3)?,
+            size_x: field(4)?,
+            size_y: field(5)?,
+        };
+        if claim.offset_x.checked_add(claim.size_x).is_none()
+            || claim.offset_y.checked_add(claim.size_y).is_none()
+        {
+            let size = capture.get(4).unwrap();
+            return Err(ParseError::new(
+                &line[size.start()..],
+                size.start() + 1,
+                "a claim that ends within 4294967295 inches",
+            ));
+        }
+        Ok(claim)
+    }
+}
+
+pub fn claims_from_input(input: &str) -> Result<Vec<Claim>> {
+    parse_lines(input)
+}
+
+#[test]
+fn test_parse_error() {
+    let error = claims_from_input("#1 @ 1,3: 4x4\n#2 @ 3,1 4x4").unwrap_err();
+    assert_eq!(
+        "Could not parse the input at line 2, column 1: expected a claim like #<id> @ <x>,<y>: <width>x<height>, found \"#2 @ 3,1 4x4\"",
+        error.to_string()
+    );
+    assert!(claims_from_input("#1 @ 1,3: 4x99999999999").is_err());
+    assert_eq!(
+        "Could not parse the input at line 1, column 11: expected a claim that ends within 4294967295 inches, found \"4x4294967295\"",
+        claims_from_input("#1 @ 1,3: 4x4294967295").unwrap_err().to_string()
+    );
+    let error = Problem3
+        .parse_reader(&mut "#1 @ 1,3: 4x4\n#2 @ 3,1 4x4".as_bytes())
+        .unwrap_err();
+    assert_eq!(
+        "Could not parse the input at line 2, column 1: expected a claim like #<id> @ <x>,<y>: <width>x<height>, found \"#2 @ 3,1 4x4\"",
+        error.to_string()
+    );
+}
+
+impl Claim {
+    /// Every square inch the claim covers.
+    fn points(&self) -> impl Iterator<Item = Point> {
+        let (x, y) = (i64::from(self.offset_x), i64::from(self.offset_y));
+        let (width, height) = (i64::from(self.size_x), i64::from(self.size_y));
+        (y..y + height).flat_map(move |y| (x..x + width).map(move |x| (x, y)))
+    }
+}
+
+/// The fabric from the top left to the bottom right of all the claims, with
+/// every square inch set to `value`, as long as it isn't too big to hold.
+fn fabric<T: Clone>(claims: &[Claim], value: T) -> Result<Grid<T>> {
+    let min = claims.iter().fold((i64::MAX, i64::MAX), |min, claim| {
+        (
+            min.0.min(i64::from(claim.offset_x)),
+            min.1.min(i64::from(claim.offset_y)),
+        )
+    });
+    let max = claims.iter().fold((i64::MIN, i64::MIN), |max, claim| {
+        (
+            max.0
+                .max(i64::from(claim.offset_x) + i64::from(claim.size_x) - 1),
+            max.1
+                .max(i64::from(claim.offset_y) + i64::from(claim.size_y) - 1),
+        )
+    });
+    Grid::new(min, max, value)
+}
+
+/// How many claims cover each square inch of the fabric.
+pub fn claim_counts(claims: &[Claim]) -> Result<Grid<u32>> {
+    let mut fabric = fabric(claims, 0)?;
+    for claim in claims {
+        for point in claim.points() {
+            fabric[point] += 1;
+        }
+    }
+    Ok(fabric)
+}
+
+pub fn part_1(claims: &[Claim]) -> Result<u32> {
+    let overlapping = claim_counts(claims)?
+        .values()
+        .filter(|&&count| count >= 2)
+        .count();
+    Ok(overlapping as u32)
+}
+
+#[cfg(test)]
+const TEST_INPUT_1: &str = r#"#1 @ 1,3: 4x4
+#2 @ 3,1: 4x4
+#3 @ 5,5: 2x2"#;
+#[test]
+fn test_part_1() {
+    assert_eq!(4, Problem3.solve_part_1(TEST_INPUT_1).unwrap());
+}
+
+pub fn part_2(claims: &[Claim]) -> Result<u32> {
+    let mut fabric: Grid<Option<u32>> = fabric(claims, None)?;
+    let mut found_ids: HashSet<u32> = HashSet::new();
+
+    for claim in claims {
+        found_ids.insert(claim.id);
+        for point in claim.points() {
+            match fabric[point] {
+                Some(other_id) => {
+                    found_ids.remove(&other_id);
+                    found_ids.remove(&claim.id);
+                }
+                None => {
+                    fabric[point] = Some(claim.id);
+                }
+            }
+        }
+    }
+
+    found_ids
+        .iter()
+        .cloned()
+        .nth(0)
+        .ok_or_else(|| "There are no elements at 0".into())
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(3, Problem3.solve_part_2(TEST_INPUT_1).unwrap());
+}
+
+#[test]
+fn test_render() {
+    let image = Problem3
+        .render(&claims_from_input(TEST_INPUT_1).unwrap())
+        .unwrap();
+    assert_eq!(((1, 1), (6, 6)), (image.min(), image.max()));
+    assert_eq!(heat(2, 2), image[(3, 3)]);
+    assert_eq!(heat(1, 2), image[(1, 3)]);
+    assert_eq!(heat(0, 2), image[(1, 1)]);
+}
+
+/// `size` claims, at least three, on a 1000 inch square of fabric. Exactly
+/// one of them overlaps no other claim.
+pub fn generate(seed: u64, size: usize) -> String {
+    type Area = (u32, u32, u32, u32);
+    let overlaps =
+        |a: Area, b: Area| a.0 < b.0 + b.2 && b.0 < a.0 + a.2 && a.1 < b.1 + b.3 && b.1 < a.1 + a.3;
+    let mut random = seeded(seed);
+    let place = |random: &mut Random| {
+        let (width, height) = (random.random_range(1..=30), random.random_range(1..=30));
+        let x = random.random_range(0..=1000 - width);
+        let y = random.random_range(0..=1000 - height);
+        (x, y, width, height)
+    };
+    // Covers the top left inch of `other`, keeping clear of `intact`.
+    let cover = |random: &mut Random, other: Area, intact: Area| {
+        let (width, height) = (random.random_range(1..=30), random.random_range(1..=30));
+        let x = random.random_range(other.0.saturating_sub(width - 1)..=other.0.min(1000 - width));
+        let y =
+            random.random_range(other.1.saturating_sub(height - 1)..=other.1.min(1000 - height));
+        if overlaps((x, y, width, height), intact) {
+            (other.0, other.1, 1, 1)
+        } else {
+            (x, y, width, height)
+        }
+    };
+
+    let size = size.max(3);
+    let intact = place(&mut random);
+    let mut areas = vec![intact];
+    // The rest come in pairs, the second covering part of the first, and an
+    // odd one out covers part of any earlier claim.
+    while areas.len() < size {
+        if areas.len() + 1 == size {
+            let other = areas[random.random_range(1..areas.len())];
+            areas.push(cover(&mut random, other, intact));
+        } else {
+            let area = loop {
+                let area = place(&mut random);
+                if !overlaps(area, intact) {
+                    break area;
+                }
+            };
+            areas.push(area);
+            areas.push(cover(&mut random, area, intact));
+        }
+    }
+    areas.shuffle(&mut random);
+    areas
+        .iter()
+        .enumerate()
+        .map(|(i, (x, y, width, height))| {
+            format!("#{} @ {},{}: {}x{}\n", i + 1, x, y, width, height)
+        })
+        .collect()
+}
+
+#[test]
+fn test_generate_leaves_one_claim_intact() {
+    let overlaps = |a: &Claim, b: &Claim| {
+        a.offset_x < b.offset_x + b.size_x
+            && b.offset_x < a.offset_x + a.size_x
+            && a.offset_y < b.offset_y + b.size_y
+            && b.offset_y < a.offset_y + a.size_y
+    };
+    for seed in 0..5 {
+        let claims = claims_from_input(&generate(seed, 200)).unwrap();
+        let intact = claims.iter().filter(|claim| {
+            claims
+                .iter()
+                .all(|other| other.id == claim.id || !overlaps(claim, other))
+        });
+        assert_eq!(1, intact.count());
+    }
+}
@@ -0,0 +1,439 @@
+#[macro_use]
+extern crate lazy_static;
+
+use aoc_common::generate::{index, seeded, IndexedRandom, Rng, SliceRandom};
+use aoc_common::image::heat;
+use aoc_common::{parse_lines_from, parse_number, Day, Grid, Image, ParseError, Result, Solution};
+use im::HashMap;
+use regex::Regex;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub const DAY: Day = Day {
+    number: 4,
+    name: "problem_4",
+    input: concat!(env!("CARGO_MANIFEST_DIR"), "/input"),
+    answers: concat!(env!("CARGO_MANIFEST_DIR"), "/answers"),
+    solution: &Problem4,
+    directions: concat!(env!("CARGO_MANIFEST_DIR"), "/directions"),
+    example: &Problem4,
+    generate,
+};
+
+pub struct Problem4;
+
+impl Solution for Problem4 {
+    type Parsed = IdSleep;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str) -> Result<IdSleep> {
+        hash_event_times(&sort_events(input)?)
+    }
+
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<IdSleep> {
+        hash_event_times(&sort_events_from(reader)?)
+    }
+
+    fn part_1(&self, id_sleep: &IdSleep) -> Result<u32> {
+        part_1(id_sleep)
+    }
+
+    fn part_2(&self, id_sleep: &IdSleep) -> Result<u32> {
+        part_2(id_sleep)
+    }
+
+    /// A row for each guard in order of id and a column for each minute,
+    /// brighter the more often the guard was asleep then.
+    fn render(&self, id_sleep: &IdSleep) -> Result<Image> {
+        let mut ids: Vec<Id> = id_sleep.keys().cloned().collect();
+        ids.sort();
+        let minutes = id_sleep
+            .values()
+            .flat_map(|sleeping| sleeping.keys().cloned());
+        let last_minute = minutes.max().unwrap_or(0).max(59);
+        let most = id_sleep
+            .values()
+            .flat_map(|sleeping| sleeping.values().cloned())
+            .max()
+            .unwrap_or(0);
+        Grid::from_fn(
+            (0, 0),
+            (i64::from(last_minute), ids.len() as i64 - 1),
+            |(minute, row)| {
+                let sleeping = &id_sleep[&ids[row as usize]];
+                heat(*sleeping.get(&(minute as u32)).unwrap_or(&0), most)
+            },
+        )
+    }
+}
+
+pub type SleepingAmount = HashMap<u32, u32>;
+
+pub fn sort_events(input: &str) -> Result<Vec<Event>> {
+    sort_events_from(input.as_bytes())
+}
+
+/// Read the events from `reader` a line at a time and sort them. Only the
+/// parsed events are kept, never the text of the log.
+pub fn sort_events_from<R: BufRead>(reader: R) -> Result<Vec<Event>> {
+    let mut events = parse_lines_from(reader, str::parse).collect::<Result<Vec<Event>>>()?;
+    events.sort();
+    Ok(events)
+}
+
+pub type IdSleep = HashMap<Id, SleepingAmount>;
+pub fn hash_event_times(events: &[Event]) -> Result<IdSleep> {
+    let mut id_sleep: IdSleep = HashMap::new();
+    let mut current_id: Option<Id> = None;
+    let mut start_time: Option<Time> = None;
+    for event in events {
+        match event.kind {
+            Kind::Sleep | Kind::Wake if current_id.is_none() => {
+                return Err(format!(
+                    "No guard is on shift to be asleep or awake at {}",
+                    event.time
+                )
+                .into());
+            }
+            Kind::StartShift { id } => {
+                current_id = Some(id);
+                start_time = None;
+            }
+            Kind::Sleep => {
+                let cloned_time = Time {
+                    year: event.time.year,
+                    month: event.time.month,
+                    day: event.time.day,
+                    hour: event.time.hour,
+                    minute: event.time.minute,
+                };
+                start_time = Some(cloned_time);
+            }
+            Kind::Wake => {
+                if let (Some(id), Some(start_time)) = (current_id, start_time) {
+                    let value = id_sleep.entry(id).or_default();
+                    for i in (start_time.minute)..event.time.minute {
+                        let time_value = value.entry(i).or_insert(0);
+                        *time_value += 1;
+                    }
+                }
+                start_time = None;
+            }
+        }
+    }
+    Ok(id_sleep)
+}
+
+pub fn part_1(id_sleep: &IdSleep) -> Result<u32> {
+    // Find the biggest time
+    let mut id_2_sleep: Vec<(Id, SleepingAmount, u32)> = id_sleep
+        .iter()
+        .map(|(&key, sleeping_time)| {
+            let total_time = sleeping_time.values().sum::<u32>();
+            (key, sleeping_time.clone(), total_time)
+        })
+        .collect();
+    id_2_sleep.sort_by_key(|&(_, _, total_time)| total_time);
+    let (left, sleeping_amount, _) = id_2_sleep.last().ok_or("No guard was ever asleep")?;
+    let mut sleeping_amount: Vec<(u32, u32)> = sleeping_amount
+        .iter()
+        .map(|(&minute, &amount)| (minute, amount))
+        .collect();
+    sleeping_amount.sort_by_key(|(_, amount)| *amount);
+    let found_time = sleeping_amount.last().ok_or("No guard was ever asleep")?;
+    answer(*left, found_time.0)
+}
+pub fn part_2(id_sleep: &IdSleep) -> Result<u32> {
+    let with_highest_sleep = id_sleep.iter().filter_map(|(&key, sleeping_time)| {
+        let total_time = sleeping_time
+            .iter()
+            .map(|(&minute, &count)| (minute, count))
+            .max_by_key(|(_, sleep_count)| *sleep_count)?;
+        Some((key, sleeping_time, total_time))
+    });
+
+    let last_item: Option<(Id, (u32, u32))> =
+        with_highest_sleep.fold(None, |acc, (id, _, (max_hour, max_hour_count))| match acc {
+            Some((_id, (_max_hour, acc_max_hour_count)))
+                if max_hour_count <= acc_max_hour_count =>
+            {
+                acc
+            }
+            _ => Some((id, (max_hour, max_hour_count))),
+        });
+    let (id, (max_hour, _)) = last_item.ok_or("No guard was ever asleep")?;
+    answer(id, max_hour)
+}
+
+fn answer(id: Id, minute: u32) -> Result<u32> {
+    id.checked_mul(minute).ok_or_else(|| {
+        format!(
+            "Guard #{} times minute {} does not fit in a u32",
+            id, minute
+        )
+        .into()
+    })
+}
+
+pub type Id = u32;
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Event {
+    time: Time,
+    kind: Kind,
+}
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Time {
+    year: u32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}-{:02}-{:02} {:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute
+        )
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Kind {
+    StartShift { id: Id },
+    Wake,
+    Sleep,
+}
+
+impl FromStr for Event {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> ::std::result::Result<Event, ParseError> {
+        const EXPECTED: &str =
+            "an event like [1518-11-01 00:00] Guard #10 begins shift, falls asleep or wakes up";
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+                r"(?x)
+                \[
+                    (?P<year>[0-9]{4})-(?P<month>[0-9]{2})-(?P<day>[0-9]{2})
+                    \s+
+                    (?P<hour>[0-9]{2}):(?P<minute>[0-9]{2})
+                \]
+                \s+
+                (?:Guard\ \#(?P<id>[0-9]+)\ begins\ shift|(?P<sleep>.+))
+            "
+            )
+            .unwrap();
+        }
+
+        let caps = match RE.captures(value) {
+            None => return Err(ParseError::new(value, 1, EXPECTED)),
+            Some(caps) => caps,
+        };
+        let field = |name: &str| {
+            let found = caps.name(name).unwrap();
+            parse_number(found.as_str(), found.start() + 1, EXPECTED)
+        };
+        let kind = if caps.name("id").is_some() {
+            Kind::StartShift { id: field("id")? }
+        } else if &caps["sleep"] == "falls asleep" {
+            Kind::Sleep
+        } else if &caps["sleep"] == "wakes up" {
+            Kind::Wake
+        } else {
+            let sleep = caps.name("sleep").unwrap();
+            return Err(ParseError::new(
+                sleep.as_str(),
+                sleep.start() + 1,
+                "\"Guard #<id> begins shift\", \"falls asleep\" or \"wakes up\"",
+            ));
+        };
+        let time = Time {
+            year: field("year")?,
+            month: field("month")?,
+            day: field("day")?,
+            hour: field("hour")?,
+            minute: field("minute")?,
+        };
+        Ok(Event { time, kind })
+    }
+}
+
+#[cfg(test)]
+const INPUT_1: &str = r#"[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up"#;
+
+#[test]
+fn test_case() {
+    let events = sort_events(INPUT_1).unwrap();
+    let id_sleep = hash_event_times(&events).unwrap();
+    assert_eq!(240, part_1(&id_sleep).unwrap());
+    assert_eq!(4455, part_2(&id_sleep).unwrap());
+}
+
+#[test]
+fn test_render() {
+    let image = Problem4.render(&Problem4.parse(INPUT_1).unwrap()).unwrap();
+    assert_eq!(((0, 0), (59, 1)), (image.min(), image.max()));
+    // Guard #10 is asleep at minute 24 twice, and #99 at 45 three times.
+    assert_eq!(heat(2, 3), image[(24, 0)]);
+    assert_eq!(heat(3, 3), image[(45, 1)]);
+    assert_eq!(heat(0, 3), image[(4, 0)]);
+}
+
+#[test]
+fn test_parse_line() {
+    assert_eq!(
+        Event {
+            time: Time {
+                year: 1518,
+                month: 11,
+                day: 1,
+                hour: 0,
+                minute: 0,
+            },
+            kind: Kind::StartShift { id: 10 },
+        },
+        "[1518-11-01 00:00] Guard #10 begins shift".parse().unwrap()
+    );
+    assert_eq!(
+        Event {
+            time: Time {
+                year: 1518,
+                month: 11,
+                day: 1,
+                hour: 0,
+                minute: 5,
+            },
+            kind: Kind::Sleep,
+        },
+        "[1518-11-01 00:05] falls asleep".parse().unwrap()
+    );
+    assert_eq!(
+        Event {
+            time: Time {
+                year: 1518,
+                month: 11,
+                day: 1,
+                hour: 0,
+                minute: 25,
+            },
+            kind: Kind::Wake,
+        },
+        "[1518-11-01 00:25] wakes up".parse().unwrap()
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    let error =
+        sort_events("[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] dozes off")
+            .unwrap_err()
+            .to_string();
+    assert_eq!(
+        "Could not parse the input at line 2, column 20: expected \"Guard #<id> begins shift\", \"falls asleep\" or \"wakes up\", found \"dozes off\"",
+        error
+    );
+}
+
+/// Guard logs for `size` shifts on consecutive days, in no particular order
+/// like the real input. Shifts begin shortly before or just after midnight
+/// and have up to three naps each.
+pub fn generate(seed: u64, size: usize) -> String {
+    const MONTHS: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    let date = |day: usize| {
+        let mut left = (day % 365) as u32;
+        let mut month = 0;
+        while left >= MONTHS[month] {
+            left -= MONTHS[month];
+            month += 1;
+        }
+        format!("{}-{:02}-{:02}", 1518 + day / 365, month + 1, left + 1)
+    };
+    let mut random = seeded(seed);
+    let guards: Vec<Id> = index::sample(&mut random, 3500, 1 + size / 15)
+        .into_iter()
+        .map(|id| id as Id + 1)
+        .collect();
+    let mut lines = vec![];
+    for day in 1..=size {
+        let guard = guards.choose(&mut random).unwrap();
+        let first_minute = if random.random_bool(0.5) {
+            lines.push(format!(
+                "[{} 23:{}] Guard #{} begins shift",
+                date(day - 1),
+                random.random_range(45..60),
+                guard
+            ));
+            0
+        } else {
+            let minute = random.random_range(0..5);
+            lines.push(format!(
+                "[{} 00:{:02}] Guard #{} begins shift",
+                date(day),
+                minute,
+                guard
+            ));
+            minute + 1
+        };
+        let naps = random.random_range(0..=3);
+        let mut minutes = index::sample(&mut random, 60 - first_minute, naps * 2).into_vec();
+        minutes.sort();
+        for nap in minutes.chunks(2) {
+            lines.push(format!(
+                "[{} 00:{:02}] falls asleep",
+                date(day),
+                first_minute + nap[0]
+            ));
+            lines.push(format!(
+                "[{} 00:{:02}] wakes up",
+                date(day),
+                first_minute + nap[1]
+            ));
+        }
+    }
+    lines.shuffle(&mut random);
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+#[test]
+fn test_no_guard_on_shift() {
+    let events =
+        sort_events("[1518-11-01 00:05] falls asleep\n[1518-11-01 00:25] wakes up").unwrap();
+    assert_eq!(
+        "No guard is on shift to be asleep or awake at 1518-11-01 00:05",
+        hash_event_times(&events).unwrap_err().to_string()
+    );
+    let id_sleep = Problem4
+        .parse("[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:25] wakes up")
+        .unwrap();
+    assert_eq!(
+        "No guard was ever asleep",
+        part_1(&id_sleep).unwrap_err().to_string()
+    );
+    assert_eq!(
+        "No guard was ever asleep",
+        part_2(&id_sleep).unwrap_err().to_string()
+    );
+}